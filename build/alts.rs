@@ -0,0 +1,58 @@
+//! Checks the alt text of every `Image` and `AnimatedImage` in posts, turning an empty or
+//! placeholder one into a compile error.
+//!
+//! Only literal alt texts can be checked; `markdown!` checks the images written in Markdown.
+
+use std::fs;
+use std::path::Path;
+
+use site_content::images;
+use site_content::source;
+
+/// Components whose `alt` is checked.
+const COMPONENTS: [&str; 2] = ["Image", "AnimatedImage"];
+
+/// A `compile_error!` for each image in the `.rs` files in `dir` whose alt text says nothing.
+pub fn check(root: &Path, dir: &Path) -> String {
+    let mut out = String::new();
+    let mut paths: Vec<_> = fs::read_dir(dir)
+        .unwrap_or_else(|e| panic!("failed to read {}: {e}", dir.display()))
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().and_then(|extension| extension.to_str()) == Some("rs"))
+        .collect();
+    paths.sort();
+
+    for path in paths {
+        let text = fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("failed to read {}: {e}", path.display()));
+        let relative = path.strip_prefix(root).unwrap_or(&path).display();
+
+        for literal in source::literals(&text) {
+            if literal.attribute.as_deref() != Some("alt")
+                || !COMPONENTS.contains(&element(&text[..literal.span.start]))
+                || images::is_meaningful_alt(&literal.value)
+            {
+                continue;
+            }
+            let message = format!(
+                "{relative}:{}: images need a meaningful alt text, got {:?}",
+                literal.line, literal.value
+            );
+            out.push_str(&format!("compile_error!({message:?});\n"));
+        }
+    }
+    out
+}
+
+/// The name of the element whose tag is open at the end of `before`, eg. `Image` for
+/// `<Image src={..} alt=`.
+fn element(before: &str) -> &str {
+    let Some(start) = before.rfind('<') else {
+        return "";
+    };
+    let tag = &before[start + 1..];
+    let len = tag
+        .find(|c: char| !c.is_alphanumeric() && c != '_' && c != ':')
+        .unwrap_or(tag.len());
+    tag[..len].rsplit("::").next().unwrap_or_default()
+}
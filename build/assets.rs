@@ -0,0 +1,156 @@
+//! Generates a typed constant for every image under `img/`, with its intrinsic dimensions read
+//! from the file so pages can reserve space for it before it loads, the content-hashed URL it's
+//! served at, the `srcset` of each format the `images` tool encodes it in and, for GIFs, the video
+//! it converts them to. GIFs get an `AnimatedAsset`, so passing any other image to
+//! `AnimatedImage` is a type error.
+//!
+//! Also generates the `asset!` macro, which maps an image's path to its constant so a typo is a
+//! compile error rather than a broken image.
+
+use std::fs;
+use std::path::Path;
+
+use site_content::images::{self, Format};
+
+/// Emits one `ImageAsset` constant per image in `dir`, nested in a module per subdirectory, so
+/// `img/blog2/kronk.gif` becomes `blog2::KRONK`. URLs are prefixed with `base_path`.
+pub fn generate(root: &Path, dir: &str, base_path: &str) -> String {
+    let mut paths = Vec::new();
+    let mut out = module(root, &root.join(dir), base_path, &[], &mut paths);
+
+    out.push_str(
+        "/// The `ImageAsset` for the image at `$path`, eg. `asset!(\"img/blog2/kronk.gif\")`.\n",
+    );
+    out.push_str("#[allow(unused_macros)]\nmacro_rules! asset {\n");
+    for (path, constant) in &paths {
+        out.push_str(&format!(
+            "    ({path:?}) => {{ $crate::assets::{constant} }};\n"
+        ));
+    }
+    out.push_str(&format!(
+        "    ($path:literal) => {{ compile_error!(concat!(\"no image at \", $path, \" (looked in {dir}/)\")) }};\n"
+    ));
+    out.push_str("}\n");
+    out
+}
+
+/// `paths` collects each image's path alongside its constant's path within the `assets` module.
+fn module(
+    root: &Path,
+    dir: &Path,
+    base_path: &str,
+    modules: &[String],
+    paths: &mut Vec<(String, String)>,
+) -> String {
+    let indent = "    ".repeat(modules.len());
+    let mut entries: Vec<_> = fs::read_dir(dir)
+        .unwrap_or_else(|e| panic!("failed to read {}: {e}", dir.display()))
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    entries.sort();
+
+    let mut out = String::new();
+    let mut names = Vec::new();
+    for path in entries {
+        let file_name = path.file_name().unwrap().to_str().unwrap();
+
+        if path.is_dir() {
+            let name = identifier(file_name);
+            out.push_str(&format!("{indent}pub mod {name} {{\n"));
+            out.push_str(&format!("{indent}    #[allow(unused_imports)]\n"));
+            out.push_str(&format!("{indent}    use super::*;\n\n"));
+            let modules = [modules, &[name]].concat();
+            out.push_str(&module(root, &path, base_path, &modules, paths));
+            out.push_str(&format!("{indent}}}\n\n"));
+            continue;
+        }
+
+        if !images::is_image(&path) {
+            continue;
+        }
+
+        let name = identifier(path.file_stem().unwrap().to_str().unwrap()).to_uppercase();
+        if names.contains(&name) {
+            panic!(
+                "{} clashes with another image named `{name}` in the same directory",
+                path.display()
+            );
+        }
+
+        let size = imagesize::size(&path)
+            .unwrap_or_else(|e| panic!("failed to read dimensions of {}: {e}", path.display()));
+        let contents =
+            fs::read(&path).unwrap_or_else(|e| panic!("failed to read {}: {e}", path.display()));
+        let source_path = path
+            .strip_prefix(root)
+            .unwrap()
+            .components()
+            .map(|c| c.as_os_str().to_str().unwrap())
+            .collect::<Vec<_>>()
+            .join("/");
+        let hashed_path = images::hashed_path(&source_path, &contents);
+        let url = format!("{base_path}/{hashed_path}");
+
+        let (width, height) = (size.width as u32, size.height as u32);
+        let sources = sources(&hashed_path, base_path, width, height);
+        let image = format!(
+            "ImageAsset {{ path: {source_path:?}, url: {url:?}, width: {width}, height: {height}, sources: &[{sources}] }}"
+        );
+        let (ty, value) = match images::animation(&hashed_path) {
+            Some(animation) => (
+                "AnimatedAsset",
+                format!(
+                    "AnimatedAsset {{ image: {image}, animation: Animation {{ video: {:?}, mime: {:?}, poster: {:?} }} }}",
+                    format!("{base_path}/{}", animation.video),
+                    images::video_type(),
+                    format!("{base_path}/{}", animation.poster),
+                ),
+            ),
+            None => ("ImageAsset", image),
+        };
+        out.push_str(&format!(
+            "{indent}#[allow(dead_code)]\n{indent}pub const {name}: {ty} = {value};\n\n",
+        ));
+
+        let constant = [modules, std::slice::from_ref(&name)].concat().join("::");
+        paths.push((source_path, constant));
+        names.push(name);
+    }
+    out
+}
+
+/// One `ImageSource` per format the image has variants in.
+fn sources(hashed_path: &str, base_path: &str, width: u32, height: u32) -> String {
+    let variants = images::variants(hashed_path, width, height);
+    Format::ALL
+        .iter()
+        .filter_map(|&format| {
+            let srcset = variants
+                .iter()
+                .filter(|variant| variant.format == format)
+                .map(|variant| format!("{base_path}/{} {}w", variant.path, variant.width))
+                .collect::<Vec<_>>()
+                .join(", ");
+            (!srcset.is_empty()).then(|| {
+                format!(
+                    "ImageSource {{ mime: {:?}, srcset: {srcset:?} }}, ",
+                    format.mime()
+                )
+            })
+        })
+        .collect()
+}
+
+/// Turns a file or directory name into a Rust identifier, eg. `gh-pages-settings` into
+/// `gh_pages_settings`.
+fn identifier(name: &str) -> String {
+    let ident: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if ident.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{ident}")
+    } else {
+        ident
+    }
+}
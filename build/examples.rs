@@ -0,0 +1,37 @@
+//! Generates the modules of the `examples` test crate from the Rust code blocks in posts'
+//! `markdown!`, so `cargo test` fails when an example stops compiling rather than readers finding
+//! out.
+//!
+//! Each example becomes a module named after where it's written, eg. `blog2_line_43`. Lines
+//! hidden with `# ` are compiled, so examples can leave out setup like imports. Examples tagged
+//! `runnable` are run as well, and ones tagged `ignore` are left out.
+
+use std::path::{Path, PathBuf};
+
+use site_content::markdown::{self, Block};
+
+pub fn generate(root: &Path, blocks: &[(PathBuf, Block)]) -> String {
+    let mut out = String::new();
+    for (path, block) in blocks {
+        let stem = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or_default();
+        for code in markdown::code_blocks(&block.markdown) {
+            if !code.is_checked() {
+                continue;
+            }
+            let line = block.line + code.line;
+            out.push_str(&format!(
+                "/// From {}:{line}.\n#[allow(dead_code, unused_imports)]\nmod {stem}_line_{line} {{\n",
+                path.strip_prefix(root).unwrap_or(path).display()
+            ));
+            out.push_str(&markdown::compiled_lines(&code.code));
+            if code.has("runnable") {
+                out.push_str("\n#[test]\nfn runs() {\n    main();\n}\n");
+            }
+            out.push_str("}\n\n");
+        }
+    }
+    out
+}
@@ -0,0 +1,76 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use site_content::versions::{self, LOCK_FILE};
+use site_content::videos::{Videos, VIDEOS};
+use site_content::{Manifest, IMAGES_DIR, MANIFEST, NOW_VAR, POSTS_DIR};
+
+mod alts;
+mod assets;
+mod examples;
+mod markdown;
+mod posts;
+mod snippets;
+mod styles;
+mod videos;
+
+const STYLES_DIR: &str = "src/styles";
+
+fn main() {
+    let root = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+
+    println!("cargo:rerun-if-changed=build");
+    println!("cargo:rerun-if-changed={IMAGES_DIR}");
+    println!("cargo:rerun-if-changed={LOCK_FILE}");
+    println!("cargo:rerun-if-changed={MANIFEST}");
+    println!("cargo:rerun-if-changed={POSTS_DIR}");
+    println!("cargo:rerun-if-changed={STYLES_DIR}");
+    println!("cargo:rerun-if-changed={VIDEOS}");
+    println!("cargo:rerun-if-env-changed={NOW_VAR}");
+
+    let manifest = Manifest::load(&root).unwrap_or_else(|e| panic!("{e}"));
+    let videos = Videos::load(&root).unwrap_or_else(|e| panic!("{e}"));
+    let locked = versions::locked(&root).unwrap_or_else(|e| panic!("{e}"));
+    if manifest.uses_git_history() {
+        // Updated by every commit and checkout.
+        println!("cargo:rerun-if-changed=.git/logs/HEAD");
+    }
+    let markdown = markdown::find(&root.join(POSTS_DIR));
+    let includes = snippets::find(&root.join(POSTS_DIR));
+    for include in &includes {
+        println!("cargo:rerun-if-changed={}", include.path);
+    }
+
+    write(
+        &out_dir.join("alts.rs"),
+        alts::check(&root, &root.join(POSTS_DIR)),
+    );
+    write(
+        &out_dir.join("assets.rs"),
+        assets::generate(&root, IMAGES_DIR, manifest.site.base_path()),
+    );
+    write(
+        &out_dir.join("examples.rs"),
+        examples::generate(&root, &markdown),
+    );
+    write(&out_dir.join("markdown.rs"), markdown::generate(&markdown));
+    write(
+        &out_dir.join("posts.rs"),
+        posts::generate(&root, &manifest, &locked),
+    );
+    write(
+        &out_dir.join("snippets.rs"),
+        snippets::generate(&root, &includes),
+    );
+    write(
+        &out_dir.join("styles.rs"),
+        styles::generate(Path::new(STYLES_DIR)),
+    );
+    write(&out_dir.join("videos.rs"), videos::generate(&videos));
+}
+
+fn write(path: &Path, contents: String) {
+    fs::write(path, contents).unwrap_or_else(|e| panic!("failed to write {}: {e}", path.display()));
+}
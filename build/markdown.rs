@@ -0,0 +1,280 @@
+//! Generates the `markdown!` macro, which renders the Markdown in a post to the `html!` it would
+//! have been written as, with GitHub-style alerts (`> [!NOTE]`) becoming `Callout`s, other
+//! blockquotes `Quote`s, footnotes (`[^1]`) `Footnote`s, `runnable` code blocks `RunnableCode`s
+//! and images under `img/` `Image`s.
+//!
+//! Like `include_file!`, only invocations found in posts get an arm, each matching its literal
+//! exactly. Markdown the site can't render, like raw HTML, is a compile error at the invocation.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use pulldown_cmark::{BlockQuoteKind, CodeBlockKind, Event, Tag, TagEnd};
+use site_content::markdown::{self, Block};
+use site_content::{images, IMAGES_DIR};
+
+const CALLOUT: &str = "crate::components::callout::Callout";
+const CALLOUT_KIND: &str = "crate::components::callout::CalloutKind";
+const FOOTNOTE: &str = "crate::components::footnotes::Footnote";
+const IMAGE: &str = "crate::components::image::Image";
+const QUOTE: &str = "crate::components::quote::Quote";
+const RUNNABLE_CODE: &str = "crate::components::runnable_code::RunnableCode";
+
+/// Every `markdown!` in the `.rs` files in `dir`, with the file it's in.
+pub fn find(dir: &Path) -> Vec<(PathBuf, Block)> {
+    let mut blocks = Vec::new();
+    let entries =
+        fs::read_dir(dir).unwrap_or_else(|e| panic!("failed to read {}: {e}", dir.display()));
+
+    for path in entries.map(|entry| entry.unwrap().path()) {
+        if path.extension().and_then(|extension| extension.to_str()) != Some("rs") {
+            continue;
+        }
+        let text = fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("failed to read {}: {e}", path.display()));
+        blocks.extend(
+            markdown::find(&text)
+                .into_iter()
+                .map(|block| (path.clone(), block)),
+        );
+    }
+    blocks.sort_by(|(a, a_block), (b, b_block)| (a, a_block.line).cmp(&(b, b_block.line)));
+    blocks
+}
+
+pub fn generate(blocks: &[(PathBuf, Block)]) -> String {
+    // Identical invocations share an arm.
+    let blocks: BTreeMap<&str, &Block> = blocks
+        .iter()
+        .map(|(_, block)| (block.literal.as_str(), block))
+        .collect();
+
+    let mut out = String::new();
+    out.push_str("/// The `Html` for the Markdown in `$markdown`, eg. `markdown!(r#\"> [!TIP]\n/// > Run `trunk serve`\"#)`. Only works in posts.\n");
+    out.push_str("#[allow(unused_macros)]\nmacro_rules! markdown {\n");
+    for (literal, block) in blocks {
+        let body = match render(&block.markdown) {
+            Ok(html) => format!("::yew::html! {{ <>{html}</> }}"),
+            Err(e) => format!("compile_error!({:?})", format!("markdown!: {e}")),
+        };
+        out.push_str(&format!("    ({literal}) => {{ {body} }};\n"));
+    }
+    out.push_str(&format!(
+        "    ($markdown:literal) => {{ compile_error!(\"markdown! only works in posts, in {}\") }};\n",
+        site_content::POSTS_DIR
+    ));
+    out.push_str("}\n");
+    out
+}
+
+/// `markdown` as the contents of an `html!`.
+fn render(markdown: &str) -> Result<String, String> {
+    // Footnotes are rendered where they're referenced, so their definitions are set aside first.
+    let (body, definitions) = markdown::footnotes(markdown);
+    let mut notes = BTreeMap::new();
+    for (label, events) in definitions {
+        notes.insert(label, render_events(events, &BTreeMap::new(), true)?);
+    }
+    render_events(body, &notes, false)
+}
+
+/// `events` as the contents of an `html!`, with `notes` holding each footnote's contents by its
+/// label. A footnote's own contents are rendered `inline`, since they end up inside a paragraph.
+fn render_events(
+    events: Vec<Event>,
+    notes: &BTreeMap<String, String>,
+    inline: bool,
+) -> Result<String, String> {
+    let (events, mut attributions) = markdown::attributions(events);
+    let mut out = String::new();
+    // What closes each element that's still open, innermost last.
+    let mut closers: Vec<String> = Vec::new();
+    // The text of a code block or an image's alt text, which is only written out at its end.
+    let mut captured: Option<String> = None;
+
+    for event in events {
+        match event {
+            // Markup inside captured text, like emphasis in alt text, is dropped.
+            Event::Start(_) if captured.is_some() => closers.push(String::new()),
+            Event::Start(tag) => {
+                let (open, close) = match tag {
+                    Tag::Paragraph if inline => (String::new(), String::new()),
+                    Tag::Paragraph => ("<p>".to_string(), "</p>".to_string()),
+                    Tag::Heading { level, .. } => (format!("<{level}>"), format!("</{level}>")),
+                    Tag::BlockQuote(Some(kind)) => {
+                        let kind = match kind {
+                            BlockQuoteKind::Note => "Note",
+                            BlockQuoteKind::Tip => "Tip",
+                            BlockQuoteKind::Important | BlockQuoteKind::Warning => "Warning",
+                            BlockQuoteKind::Caution => "Danger",
+                        };
+                        (
+                            format!("<{CALLOUT} kind={{{CALLOUT_KIND}::{kind}}}>"),
+                            format!("</{CALLOUT}>"),
+                        )
+                    }
+                    Tag::BlockQuote(None) => {
+                        let props = match attributions.pop_front().flatten() {
+                            Some(markdown::Attribution { text, source }) => {
+                                let source = source
+                                    .map(|source| format!(" source={source:?}"))
+                                    .unwrap_or_default();
+                                format!(" attribution={text:?}{source}")
+                            }
+                            None => String::new(),
+                        };
+                        (format!("<{QUOTE}{props}>"), format!("</{QUOTE}>"))
+                    }
+                    Tag::CodeBlock(_) | Tag::Image { .. } => {
+                        captured = Some(String::new());
+                        (String::new(), render_end(&tag))
+                    }
+                    Tag::List(Some(1)) => ("<ol>".to_string(), "</ol>".to_string()),
+                    Tag::List(Some(start)) => (
+                        format!("<ol start={:?}>", start.to_string()),
+                        "</ol>".to_string(),
+                    ),
+                    Tag::List(None) => ("<ul>".to_string(), "</ul>".to_string()),
+                    Tag::Item => ("<li>".to_string(), "</li>".to_string()),
+                    Tag::Emphasis => ("<em>".to_string(), "</em>".to_string()),
+                    Tag::Strong => ("<strong>".to_string(), "</strong>".to_string()),
+                    Tag::Strikethrough => ("<s>".to_string(), "</s>".to_string()),
+                    Tag::Link {
+                        dest_url, title, ..
+                    } => {
+                        let title = if title.is_empty() {
+                            String::new()
+                        } else {
+                            format!(" title={:?}", &*title)
+                        };
+                        (
+                            format!("<a href={:?}{title}>", &*dest_url),
+                            "</a>".to_string(),
+                        )
+                    }
+                    Tag::HtmlBlock => {
+                        return Err("raw HTML isn't supported, use html! instead".into())
+                    }
+                    other => return Err(format!("{other:?} isn't supported")),
+                };
+                out.push_str(&open);
+                closers.push(close);
+            }
+            Event::End(end) => {
+                let close = closers.pop().unwrap_or_default();
+                if matches!(end, TagEnd::CodeBlock | TagEnd::Image) {
+                    let text = captured.take().unwrap_or_default();
+                    if end == TagEnd::Image
+                        && close.starts_with(&format!("<{IMAGE} "))
+                        && !images::is_meaningful_alt(&text)
+                    {
+                        return Err(format!("images need a meaningful alt text, got {text:?}"));
+                    }
+                    out.push_str(&fill(&close, &text));
+                } else if captured.is_none() {
+                    out.push_str(&close);
+                }
+            }
+            Event::Text(text) => match &mut captured {
+                Some(captured) => captured.push_str(&text),
+                None => out.push_str(&format!("{{ {:?} }}", &*text)),
+            },
+            Event::Code(code) => match &mut captured {
+                Some(captured) => captured.push_str(&code),
+                None => out.push_str(&format!(
+                    "<span class=\"code-snippet\">{{ {:?} }}</span>",
+                    &*code
+                )),
+            },
+            Event::FootnoteReference(_) if captured.is_some() => {}
+            Event::FootnoteReference(label) => match notes.get(&*label) {
+                Some(note) => out.push_str(&format!("<{FOOTNOTE}>{note}</{FOOTNOTE}>")),
+                None if inline => return Err(format!("footnote [^{label}] can't have footnotes")),
+                None => return Err(format!("footnote [^{label}] isn't defined")),
+            },
+            Event::SoftBreak => match &mut captured {
+                Some(captured) => captured.push(' '),
+                None => out.push_str("{ \" \" }"),
+            },
+            Event::HardBreak => out.push_str("<br />"),
+            Event::Rule => out.push_str("<hr />"),
+            Event::Html(_) | Event::InlineHtml(_) => {
+                return Err("raw HTML isn't supported, use html! instead".into())
+            }
+            other => return Err(format!("{other:?} isn't supported")),
+        }
+    }
+    Ok(out)
+}
+
+/// What replaces an element whose contents are captured rather than rendered as they come, with
+/// placeholders standing in for the contents: `{captured}` as they are, and for Rust, `{shown}`
+/// without hidden lines and `{compiled}` with them.
+fn render_end(tag: &Tag) -> String {
+    match tag {
+        Tag::CodeBlock(kind) => {
+            let info = match kind {
+                CodeBlockKind::Fenced(info) => info,
+                CodeBlockKind::Indented => "",
+            };
+            // eg. ```rust,runnable
+            let mut attributes = info.split(',').map(str::trim);
+            let language = attributes.next().unwrap_or_default();
+            let runnable = attributes.any(|attribute| attribute == "runnable");
+            match (language, runnable) {
+                (_, true) => format!("<{RUNNABLE_CODE} code={{compiled}} shown={{shown}} />"),
+                ("rust", false) => {
+                    "<pre class=\"code-snippet\" data-language=\"rust\"><code>{ {shown} }</code></pre>"
+                        .to_string()
+                }
+                _ => format!(
+                    "<pre class=\"code-snippet\" data-language={language:?}><code>{{ {{captured}} }}</code></pre>"
+                ),
+            }
+        }
+        Tag::Image {
+            dest_url, title, ..
+        } => {
+            let title = if title.is_empty() {
+                String::new()
+            } else {
+                format!(" title={:?}", &**title)
+            };
+            if dest_url.starts_with(&format!("{IMAGES_DIR}/")) {
+                format!(
+                    "<{IMAGE} src={{asset!({:?})}} alt={{captured}} />",
+                    &**dest_url
+                )
+            } else {
+                format!("<img src={:?} alt={{captured}}{title} />", &**dest_url)
+            }
+        }
+        _ => String::new(),
+    }
+}
+
+/// `close` from `render_end` with its placeholders replaced by `text`.
+fn fill(close: &str, text: &str) -> String {
+    let values = [
+        ("{captured}", text.to_string()),
+        ("{shown}", markdown::shown_lines(text)),
+        ("{compiled}", markdown::compiled_lines(text)),
+    ];
+
+    let mut out = String::new();
+    let mut rest = close;
+    // The contents could contain a placeholder themselves, so each is only looked for in what's
+    // left of `close`.
+    while let Some((start, placeholder, value)) = values
+        .iter()
+        .filter_map(|(placeholder, value)| Some((rest.find(placeholder)?, placeholder, value)))
+        .min_by_key(|(start, ..)| *start)
+    {
+        out.push_str(&rest[..start]);
+        out.push_str(&format!("{value:?}"));
+        rest = &rest[start + placeholder.len()..];
+    }
+    out.push_str(rest);
+    out
+}
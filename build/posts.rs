@@ -0,0 +1,87 @@
+//! Turns `posts.toml` into the `Post` constants in `crate::posts`, counting each post's words so
+//! reading times don't have to be maintained by hand, and comparing the crate versions each was
+//! written against with the ones the site now locks.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use site_content::{source, versions, Date, Manifest};
+
+/// `locked` maps each of the site's dependencies to its version in `Cargo.lock`.
+pub fn generate(root: &Path, manifest: &Manifest, locked: &BTreeMap<String, String>) -> String {
+    let site = &manifest.site;
+    let mut out = format!(
+        "pub const SITE_TITLE: &str = {:?};\npub const SITE_AUTHOR: &str = {:?};\npub const FEED_URL: &str = {:?};\n\n",
+        site.title,
+        site.author,
+        format!("{}/feed.xml", site.base_path()),
+    );
+    let now = Date::now().unwrap_or_else(|e| panic!("{e}"));
+    out.push_str(&format!(
+        "/// The day the site was built as of; posts dated later weren't published yet.\npub const BUILT_ON: Date = {};\n\n",
+        date_expr(now),
+    ));
+
+    for post in &manifest.posts {
+        let path = post.source(root);
+        let text = fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("failed to read {}: {e}", path.display()));
+
+        out.push_str(&format!(
+            "pub const {name}: Post = Post {{\n    slug: {slug:?},\n    route: Route::{variant},\n    title: {title:?},\n    date: {date},\n    tags: &{tags:?},\n    words: {words},\n    draft: {draft},\n    updated: {updated},\n    revisions: &[{revisions}],\n    outdated: &[{outdated}],\n}};\n\n",
+            name = post.slug.to_uppercase(),
+            slug = post.slug,
+            variant = post.route_variant(),
+            title = post.title,
+            date = date_expr(post.date),
+            tags = post.tags,
+            words = source::count_words(&text),
+            draft = post.draft,
+            updated = match post.last_updated() {
+                Some(date) => format!("Some({})", date_expr(date)),
+                None => "None".to_string(),
+            },
+            revisions = post
+                .revisions
+                .iter()
+                .map(|revision| format!(
+                    "Revision {{ date: {}, note: {:?} }}",
+                    date_expr(revision.date),
+                    revision.note
+                ))
+                .collect::<Vec<_>>()
+                .join(", "),
+            outdated = post
+                .crates
+                .iter()
+                .filter_map(|(name, written_for)| {
+                    let current = locked.get(name);
+                    if current.is_some_and(|current| versions::is_compatible(written_for, current)) {
+                        return None;
+                    }
+                    Some(format!(
+                        "OutdatedCrate {{ name: {name:?}, written_for: {written_for:?}, current: {current:?} }}"
+                    ))
+                })
+                .collect::<Vec<_>>()
+                .join(", "),
+        ));
+    }
+
+    let names: Vec<_> = manifest
+        .posts
+        .iter()
+        .map(|post| post.slug.to_uppercase())
+        .collect();
+    out.push_str(&format!(
+        "/// Every post, newest first.\npub const POSTS: &[Post] = &[{}];\n",
+        names.join(", ")
+    ));
+
+    out
+}
+
+fn date_expr(date: Date) -> String {
+    format!("Date::new({}, {}, {})", date.year, date.month, date.day)
+}
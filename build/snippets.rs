@@ -0,0 +1,268 @@
+//! Generates the `include_file!` macro, which turns a repo file (or some of its lines) named in a
+//! post into a syntax-highlighted `Snippet`, so tutorials show the real, current file rather than
+//! a copy that drifts from it.
+//!
+//! Lines are picked by text they contain rather than by number, so the excerpt follows them when
+//! the file changes around them. Only invocations found in posts get an arm, since each needs the
+//! file read and highlighted here. A missing file, or text it doesn't contain, becomes a compile
+//! error at the invocation.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Component, Path};
+
+use site_content::source;
+use syntect::parsing::{ParseState, ScopeStack, SyntaxSet};
+
+const MACRO: &str = "include_file!(";
+
+/// The file an `include_file!` names and, if it only wants some of its lines, text in the first
+/// and last of them.
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+pub struct Include {
+    pub path: String,
+    pub lines: Option<(String, String)>,
+}
+
+/// Scope prefixes and the `Highlight` variant text in them is shown with. The innermost scope
+/// with a match wins.
+const HIGHLIGHTS: [(&str, &str); 9] = [
+    ("comment", "Comment"),
+    ("string", "String"),
+    ("constant", "Constant"),
+    ("keyword", "Keyword"),
+    ("storage", "Keyword"),
+    ("entity.name.tag", "Keyword"),
+    ("entity.other.attribute-name", "Name"),
+    ("entity.name", "Name"),
+    ("support.function", "Name"),
+];
+
+/// Every distinct `include_file!` in the `.rs` files in `dir`, eg. `include_file!("404.html")` or
+/// `include_file!("index.html", "<script"..="</script>")`.
+pub fn find(dir: &Path) -> BTreeSet<Include> {
+    let mut includes = BTreeSet::new();
+    let entries =
+        fs::read_dir(dir).unwrap_or_else(|e| panic!("failed to read {}: {e}", dir.display()));
+
+    for path in entries.map(|entry| entry.unwrap().path()) {
+        if path.extension().and_then(|extension| extension.to_str()) != Some("rs") {
+            continue;
+        }
+        let text = fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("failed to read {}: {e}", path.display()));
+
+        for (start, _) in text.match_indices(MACRO) {
+            let args = &text[start + MACRO.len()..];
+            let args = &args[..end(args)];
+            let mut literals = source::literals(args).into_iter();
+            let Some(path) = literals.next() else {
+                continue;
+            };
+            // Anything after the path is the range of lines; the macro's fallback arm reports
+            // ones that don't parse.
+            let lines = match (literals.next(), literals.next(), literals.next()) {
+                (None, ..) if args[path.span.end..].trim().is_empty() => None,
+                (Some(first), Some(last), None)
+                    if args[path.span.end..first.span.start].trim() == ","
+                        && args[first.span.end..last.span.start].trim() == "..="
+                        && args[last.span.end..].trim().is_empty() =>
+                {
+                    Some((first.value, last.value))
+                }
+                _ => continue,
+            };
+            includes.insert(Include {
+                path: path.value,
+                lines,
+            });
+        }
+    }
+    includes
+}
+
+pub fn generate(root: &Path, includes: &BTreeSet<Include>) -> String {
+    let syntaxes = SyntaxSet::load_defaults_newlines();
+
+    let mut out = String::new();
+    out.push_str("/// A `Snippet` of the repo file at `$path`, optionally only from the first line containing\n");
+    out.push_str("/// `$first` to the next containing `$last`, eg.\n");
+    out.push_str(
+        "/// `include_file!(\"index.html\", \"<script\"..=\"</script>\")`. Only works in posts.\n",
+    );
+    out.push_str("#[allow(unused_macros)]\nmacro_rules! include_file {\n");
+    for include in includes {
+        let pattern = match &include.lines {
+            Some((first, last)) => format!("{:?}, {first:?}..={last:?}", include.path),
+            None => format!("{:?}", include.path),
+        };
+        let body = snippet(root, &syntaxes, include)
+            .unwrap_or_else(|e| format!("compile_error!({:?})", format!("include_file!: {e}")));
+        out.push_str(&format!("    ({pattern}) => {{ {body} }};\n"));
+    }
+    out.push_str(&format!(
+        "    ($path:literal $($rest:tt)*) => {{ compile_error!(concat!(\"include_file! for \", $path, \" wasn't found in {}, or its lines aren't written \\\"first\\\"..=\\\"last\\\"\")) }};\n",
+        site_content::POSTS_DIR
+    ));
+    out.push_str("}\n");
+    out
+}
+
+/// The `Snippet` expression for `include`.
+fn snippet(root: &Path, syntaxes: &SyntaxSet, include: &Include) -> Result<String, String> {
+    let relative = Path::new(&include.path);
+    if !relative
+        .components()
+        .all(|component| matches!(component, Component::Normal(_)))
+    {
+        return Err(format!(
+            "{} must be relative to the repo's root",
+            include.path
+        ));
+    }
+    let path = root.join(relative);
+    let text =
+        fs::read_to_string(&path).map_err(|e| format!("failed to read {}: {e}", include.path))?;
+
+    let mut lines = highlight(syntaxes, relative, &text)?;
+    let total_lines = lines.len();
+    let first = match &include.lines {
+        Some((first, last)) => {
+            let (first, last) = find_lines(&text, first, last).map_err(|missing| {
+                format!("{} has no line containing {missing:?}", include.path)
+            })?;
+            lines.truncate(last);
+            lines.drain(..first - 1);
+            dedent(&mut lines);
+            first
+        }
+        None => 1,
+    };
+
+    let lines: Vec<String> = lines
+        .iter()
+        .map(|tokens| {
+            let tokens: Vec<String> = tokens
+                .iter()
+                .map(|(highlight, text)| {
+                    format!("($crate::snippets::Highlight::{highlight}, {text:?})")
+                })
+                .collect();
+            format!("&[{}]", tokens.join(", "))
+        })
+        .collect();
+    Ok(format!(
+        "$crate::snippets::Snippet {{ path: {:?}, first_line: {first}, total_lines: {total_lines}, lines: &[{}] }}",
+        include.path,
+        lines.join(", ")
+    ))
+}
+
+/// The 1-based numbers of the first line in `text` containing `first` and the next containing
+/// `last`, which can be the same line, or whichever of the two wasn't found.
+fn find_lines<'a>(text: &str, first: &'a str, last: &'a str) -> Result<(usize, usize), &'a str> {
+    let lines: Vec<&str> = text.lines().collect();
+    let start = lines
+        .iter()
+        .position(|line| line.contains(first))
+        .ok_or(first)?;
+    let end = lines[start..]
+        .iter()
+        .position(|line| line.contains(last))
+        .ok_or(last)?;
+    Ok((start + 1, start + end + 1))
+}
+
+/// The length of an `include_file!`'s arguments, up to the `)` that isn't inside a string.
+fn end(args: &str) -> usize {
+    let literals = source::literals(args);
+    args.char_indices()
+        .find(|(i, c)| *c == ')' && !literals.iter().any(|literal| literal.span.contains(i)))
+        .map_or(args.len(), |(i, _)| i)
+}
+
+/// Splits `text` into lines of `(Highlight variant, text)` tokens, merging neighbouring tokens
+/// that look the same. Files of a type with no syntax definition come out plain.
+fn highlight(
+    syntaxes: &SyntaxSet,
+    path: &Path,
+    text: &str,
+) -> Result<Vec<Vec<(&'static str, String)>>, String> {
+    let syntax = syntaxes
+        .find_syntax_for_file(path)
+        .ok()
+        .flatten()
+        .unwrap_or_else(|| syntaxes.find_syntax_plain_text());
+    let mut state = ParseState::new(syntax);
+    let mut stack = ScopeStack::new();
+
+    let mut lines = Vec::new();
+    for line in text.split_inclusive('\n') {
+        let ops = state
+            .parse_line(line, syntaxes)
+            .map_err(|e| format!("failed to highlight {}: {e}", path.display()))?;
+
+        let mut tokens = Vec::new();
+        let mut start = 0;
+        for (index, op) in &ops {
+            push(&mut tokens, classify(&stack), &line[start..*index]);
+            stack
+                .apply(op)
+                .map_err(|e| format!("failed to highlight {}: {e:?}", path.display()))?;
+            start = *index;
+        }
+        push(&mut tokens, classify(&stack), &line[start..]);
+        lines.push(tokens);
+    }
+    Ok(lines)
+}
+
+/// Appends `text` to a line's `tokens`, leaving out its line ending.
+fn push(tokens: &mut Vec<(&'static str, String)>, highlight: &'static str, text: &str) {
+    let text = text.trim_end_matches(['\n', '\r']);
+    if text.is_empty() {
+        return;
+    }
+    match tokens.last_mut() {
+        Some((last, last_text)) if *last == highlight => last_text.push_str(text),
+        _ => tokens.push((highlight, text.to_string())),
+    }
+}
+
+fn classify(stack: &ScopeStack) -> &'static str {
+    stack
+        .as_slice()
+        .iter()
+        .rev()
+        .find_map(|scope| {
+            let scope = scope.build_string();
+            HIGHLIGHTS
+                .iter()
+                .find(|(prefix, _)| scope == *prefix || scope.starts_with(&format!("{prefix}.")))
+                .map(|(_, highlight)| *highlight)
+        })
+        .unwrap_or("Plain")
+}
+
+/// Removes the indentation every non-blank line in an excerpt shares.
+fn dedent(lines: &mut [Vec<(&'static str, String)>]) {
+    let indent = lines
+        .iter()
+        .filter_map(|tokens| {
+            let text: String = tokens.iter().map(|(_, text)| text.as_str()).collect();
+            let content = text.trim_start_matches([' ', '\t']);
+            (!content.is_empty()).then(|| text.len() - content.len())
+        })
+        .min()
+        .unwrap_or(0);
+
+    for tokens in lines.iter_mut() {
+        let mut remaining = indent;
+        for (_, text) in tokens.iter_mut() {
+            let strip = text.len().min(remaining);
+            text.drain(..strip);
+            remaining -= strip;
+        }
+        tokens.retain(|(_, text)| !text.is_empty());
+    }
+}
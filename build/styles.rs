@@ -0,0 +1,76 @@
+//! Validates every stylesheet in `src/styles` and compiles it into a `css!` invocation, so a
+//! broken stylesheet fails the build instead of panicking in the browser.
+
+use std::fs;
+use std::path::Path;
+use std::process;
+
+use stylist_core::ast::Sheet;
+use stylist_core::Error;
+
+/// Emits a `pub fn <stem>() -> StyleSource` for every `.css` file in `dir`.
+pub fn generate(dir: &Path) -> String {
+    let mut entries: Vec<_> = fs::read_dir(dir)
+        .unwrap_or_else(|e| panic!("failed to read {}: {e}", dir.display()))
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "css"))
+        .collect();
+    entries.sort();
+
+    let mut out = String::new();
+    for path in entries {
+        let css = fs::read_to_string(&path).unwrap();
+        if let Err(error) = css.parse::<Sheet>() {
+            report(&path, &css, error);
+            process::exit(1);
+        }
+
+        let stem = path
+            .file_stem()
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .replace('-', "_");
+        let hashes = "#".repeat(longest_hash_run(&css) + 1);
+        out.push_str(&format!(
+            "/// Compiled from `{}`.\npub fn {stem}() -> ::stylist::StyleSource {{\n    ::stylist::css!(r{hashes}\"{css}\"{hashes})\n}}\n\n",
+            path.display(),
+        ));
+    }
+    out
+}
+
+fn report(path: &Path, css: &str, error: Error) {
+    let Error::Parse { reason, source } = error else {
+        eprintln!("error: {}: {error}", path.display());
+        return;
+    };
+
+    // nom records the unparsed remainder of the input; the innermost entry is where parsing
+    // actually stopped.
+    let offset = source
+        .and_then(|source| {
+            source
+                .errors
+                .first()
+                .map(|(rest, _)| css.len() - rest.len())
+        })
+        .unwrap_or(0);
+    let line = css[..offset].matches('\n').count() + 1;
+    let column = offset - css[..offset].rfind('\n').map_or(0, |i| i + 1) + 1;
+
+    eprintln!(
+        "error: invalid CSS at {}:{line}:{column}\n{reason}",
+        path.display()
+    );
+}
+
+/// Length of the longest run of `#` directly after a `"`, so the raw string literal we wrap the
+/// stylesheet in can't be terminated early.
+fn longest_hash_run(css: &str) -> usize {
+    css.split('"')
+        .skip(1)
+        .map(|rest| rest.chars().take_while(|&c| c == '#').count())
+        .max()
+        .unwrap_or(0)
+}
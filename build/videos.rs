@@ -0,0 +1,24 @@
+//! Turns `videos.toml` into the `video!` macro, which maps a YouTube id to its `Video` so
+//! embedding a video missing from the file is a compile error.
+
+use site_content::videos::Videos;
+
+pub fn generate(videos: &Videos) -> String {
+    let mut out = String::new();
+    out.push_str("/// The `Video` with YouTube id `$id`, eg. `video!(\"P4LMfkFLRsI\")`.\n");
+    out.push_str("#[allow(unused_macros)]\nmacro_rules! video {\n");
+    for video in &videos.videos {
+        out.push_str(&format!(
+            "    ({id:?}) => {{ $crate::videos::Video {{ id: {id:?}, title: {title:?}, channel: {channel:?}, thumbnail: asset!({thumbnail:?}) }} }};\n",
+            id = video.id,
+            title = video.title,
+            channel = video.channel,
+            thumbnail = video.thumbnail,
+        ));
+    }
+    out.push_str(
+        "    ($id:literal) => { compile_error!(concat!(\"no video \", $id, \" in videos.toml\")) };\n",
+    );
+    out.push_str("}\n");
+    out
+}
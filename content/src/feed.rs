@@ -0,0 +1,122 @@
+//! Atom feed of every published post.
+
+use crate::{Date, Manifest, Site};
+
+/// Renders the posts in `manifest` that are published as of `today` as an Atom 1.0 document.
+pub fn atom(manifest: &Manifest, today: Date) -> String {
+    let site = &manifest.site;
+    let posts: Vec<_> = manifest
+        .posts
+        .iter()
+        .filter(|post| post.is_published(today))
+        .collect();
+    let updated = posts
+        .iter()
+        .map(|post| post.last_updated().unwrap_or(post.date))
+        .max()
+        .map(timestamp)
+        .unwrap_or_default();
+
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+    xml.push_str("<feed xmlns=\"http://www.w3.org/2005/Atom\">\n");
+    xml.push_str(&format!("  <title>{}</title>\n", escape(&site.title)));
+    let home = escape(&site.absolute_url("/"));
+    xml.push_str(&format!("  <id>{home}</id>\n"));
+    xml.push_str(&format!("  <link href=\"{home}\"/>\n"));
+    xml.push_str(&format!(
+        "  <link rel=\"self\" href=\"{}\"/>\n",
+        escape(&site.absolute_url("/feed.xml"))
+    ));
+    xml.push_str(&format!("  <updated>{updated}</updated>\n"));
+    xml.push_str(&format!(
+        "  <author><name>{}</name></author>\n",
+        escape(&site.author)
+    ));
+
+    for post in posts {
+        let url = escape(&site.absolute_url(&post.path()));
+        xml.push_str("  <entry>\n");
+        xml.push_str(&format!("    <title>{}</title>\n", escape(&post.title)));
+        xml.push_str(&format!("    <id>{url}</id>\n"));
+        xml.push_str(&format!("    <link href=\"{url}\"/>\n"));
+        xml.push_str(&format!(
+            "    <published>{}</published>\n",
+            timestamp(post.date)
+        ));
+        xml.push_str(&format!(
+            "    <updated>{}</updated>\n",
+            timestamp(post.last_updated().unwrap_or(post.date))
+        ));
+        for tag in &post.tags {
+            xml.push_str(&format!("    <category term=\"{}\"/>\n", escape(tag)));
+        }
+        xml.push_str("  </entry>\n");
+    }
+
+    xml.push_str("</feed>\n");
+    xml
+}
+
+/// `html`, the built site's `index.html`, with a `<link>` in its `<head>` that lets feed readers
+/// find the feed. It's added after the build rather than written in `index.html` so its URL
+/// follows the site's base path. `None` if `html` has no `</head>`.
+pub fn with_link(site: &Site, html: &str) -> Option<String> {
+    let link = format!(
+        "<link rel=\"alternate\" type=\"application/atom+xml\" title=\"{}\" href=\"{}\" />\n",
+        escape(&site.title),
+        escape(&site.absolute_url("/feed.xml")),
+    );
+    if html.contains(&link) {
+        return Some(html.to_string());
+    }
+    let end = html.find("</head>")?;
+    Some(format!("{}{link}{}", &html[..end], &html[end..]))
+}
+
+fn timestamp(date: crate::Date) -> String {
+    format!("{date}T00:00:00Z")
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn site(url: &str) -> Site {
+        Site {
+            title: "Supa Site".to_string(),
+            author: String::new(),
+            url: url.to_string(),
+        }
+    }
+
+    #[test]
+    fn link_follows_the_base_path() {
+        let html = with_link(
+            &site("https://example.github.io/rust-site"),
+            "<html><head><title>Supa</title></head><body></body></html>",
+        )
+        .unwrap();
+        assert_eq!(
+            html,
+            "<html><head><title>Supa</title><link rel=\"alternate\" \
+             type=\"application/atom+xml\" title=\"Supa Site\" \
+             href=\"https://example.github.io/rust-site/feed.xml\" />\n</head><body></body></html>"
+        );
+    }
+
+    #[test]
+    fn link_is_added_once() {
+        let site = site("https://supa.fish");
+        let once = with_link(&site, "<head></head>").unwrap();
+        assert_eq!(with_link(&site, &once).unwrap(), once);
+        assert_eq!(with_link(&site, "<body></body>"), None);
+    }
+}
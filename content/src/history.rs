@@ -0,0 +1,41 @@
+//! Revisions read from the git history of a post's source.
+
+use std::path::Path;
+use std::process::Command;
+
+use crate::Revision;
+
+/// A revision for every commit that touched `path`, newest first, noted with the commit's
+/// subject.
+pub fn git_log(root: &Path, path: &Path) -> Result<Vec<Revision>, String> {
+    let output = Command::new("git")
+        .current_dir(root)
+        .args([
+            "log",
+            "--follow",
+            "--date=short",
+            "--format=%ad%x09%s",
+            "--",
+        ])
+        .arg(path)
+        .output()
+        .map_err(|e| format!("failed to run git: {e}"))?;
+    if !output.status.success() {
+        return Err(format!(
+            "git log failed for {}: {}",
+            path.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| line.split_once('\t'))
+        .map(|(date, subject)| {
+            Ok(Revision {
+                date: date.parse()?,
+                note: subject.to_string(),
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,170 @@
+//! Content-hashed copies and resized, re-encoded variants of the images under `img/`, and the
+//! videos GIFs are converted to.
+//!
+//! The build script links to them and the `images` tool writes them next to the built site. Both
+//! go through [`hashed_path`], [`variants`] and [`animation`], so the two always agree on what
+//! exists.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// File extensions treated as images.
+pub const EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "gif", "webp"];
+
+/// Alt texts that say nothing a screen reader user couldn't already guess.
+pub const PLACEHOLDER_ALTS: &[&str] = &["image", "img", "picture", "photo", "screenshot"];
+
+/// Whether `alt` describes an image, rather than being empty or a placeholder like `image`.
+pub fn is_meaningful_alt(alt: &str) -> bool {
+    let alt = alt.trim();
+    !alt.is_empty() && !PLACEHOLDER_ALTS.contains(&alt.to_lowercase().as_str())
+}
+
+/// Widths, in pixels, every image is also offered at when it's wider than that.
+pub const WIDTHS: &[u32] = &[480, 800, 1200];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Avif,
+    Webp,
+}
+
+impl Format {
+    /// Every format variants are encoded in, most compact first, which is the order browsers
+    /// should consider them in.
+    pub const ALL: [Format; 2] = [Format::Avif, Format::Webp];
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Format::Avif => "avif",
+            Format::Webp => "webp",
+        }
+    }
+
+    pub fn mime(self) -> &'static str {
+        match self {
+            Format::Avif => "image/avif",
+            Format::Webp => "image/webp",
+        }
+    }
+}
+
+/// The AV1 level (`seq_level_idx`) the `images` tool encodes GIFs at, which caps their size: 4 is
+/// level 3.0, up to 665,856 pixels a frame.
+pub const VIDEO_LEVEL: u8 = 4;
+
+/// The `type` of the videos GIFs are converted to: AV1 in WebM, in the main profile at
+/// [`VIDEO_LEVEL`] and main tier, with the 8-bit depth the `images` tool encodes at.
+pub fn video_type() -> String {
+    format!("video/webm; codecs=\"av01.0.{VIDEO_LEVEL:02}M.08\"")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Variant {
+    /// Path relative to the site root, eg. `img/blog2/app-1f0a9c3e5b7d2468-480.avif`.
+    pub path: String,
+    pub width: u32,
+    pub height: u32,
+    pub format: Format,
+}
+
+/// The muted, looping video a GIF is converted to, and the still of its first frame shown until
+/// it plays.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Animation {
+    /// Path relative to the site root, eg. `img/blog2/kronk-1f0a9c3e5b7d2468.webm`.
+    pub video: String,
+    /// Path relative to the site root, eg. `img/blog2/kronk-1f0a9c3e5b7d2468-poster.jpg`.
+    pub poster: String,
+}
+
+pub fn is_image(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+}
+
+/// The path an image with `contents` is served at, eg. `img/blog2/kronk-1f0a9c3e5b7d2468.gif`
+/// for `img/blog2/kronk.gif`. The name changes whenever the image does, so it can be cached
+/// forever.
+pub fn hashed_path(path: &str, contents: &[u8]) -> String {
+    let hash = fnv1a(contents);
+    match path.rsplit_once('.') {
+        Some((stem, extension)) => format!("{stem}-{hash:016x}.{extension}"),
+        None => format!("{path}-{hash:016x}"),
+    }
+}
+
+/// 64-bit FNV-1a: not cryptographic, but stable across Rust versions and platforms, unlike
+/// `std`'s hasher.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Every image under `dir`, recursively, in a stable order.
+pub fn find(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let mut entries = fs::read_dir(dir)
+        .map_err(|e| format!("failed to read {}: {e}", dir.display()))?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("failed to read {}: {e}", dir.display()))?;
+    entries.sort();
+
+    let mut images = Vec::new();
+    for path in entries {
+        if path.is_dir() {
+            images.extend(find(&path)?);
+        } else if is_image(&path) {
+            images.push(path);
+        }
+    }
+    Ok(images)
+}
+
+/// The video and poster a GIF served at `path` (its [`hashed_path`]) is converted to, or `None`
+/// if it isn't a GIF.
+pub fn animation(path: &str) -> Option<Animation> {
+    let (stem, extension) = path.rsplit_once('.')?;
+    extension.eq_ignore_ascii_case("gif").then(|| Animation {
+        video: format!("{stem}.webm"),
+        poster: format!("{stem}-poster.jpg"),
+    })
+}
+
+/// The variants of the `width`x`height` image served at `path` (its [`hashed_path`]), smallest
+/// first.
+///
+/// GIFs get none, since they're converted to video instead (see [`animation`]), and an image is
+/// never re-encoded in the format it's already in: our encoder only does lossless WebP, which
+/// would come out larger than a lossy original.
+pub fn variants(path: &str, width: u32, height: u32) -> Vec<Variant> {
+    let Some((stem, extension)) = path.rsplit_once('.') else {
+        return Vec::new();
+    };
+    let extension = extension.to_lowercase();
+    if extension == "gif" || width == 0 {
+        return Vec::new();
+    }
+
+    let mut widths: Vec<u32> = WIDTHS.iter().copied().filter(|&w| w < width).collect();
+    widths.push(width);
+
+    let mut variants = Vec::new();
+    for format in Format::ALL {
+        if format.extension() == extension {
+            continue;
+        }
+        for &w in &widths {
+            let h = (u64::from(height) * u64::from(w) + u64::from(width) / 2) / u64::from(width);
+            variants.push(Variant {
+                path: format!("{stem}-{w}.{}", format.extension()),
+                width: w,
+                height: h.max(1) as u32,
+                format,
+            });
+        }
+    }
+    variants
+}
@@ -0,0 +1,294 @@
+//! Post metadata and source analysis shared by the site's build script and its tools.
+//!
+//! Everything here runs on the host at build time; none of it is compiled into the site itself.
+
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::Deserialize;
+
+pub mod feed;
+pub mod history;
+pub mod images;
+pub mod links;
+pub mod markdown;
+pub mod source;
+pub mod versions;
+pub mod videos;
+
+/// Name of the manifest describing every post, relative to the site's root.
+pub const MANIFEST: &str = "posts.toml";
+
+/// Directory holding the site's images, relative to the site's root.
+pub const IMAGES_DIR: &str = "img";
+
+/// Directory holding each post's page component, relative to the site's root.
+pub const POSTS_DIR: &str = "src/pages";
+
+/// Environment variable that sets the day posts are published as of, as `YYYY-MM-DD`, to build
+/// the site as it will be on that day.
+pub const NOW_VAR: &str = "SITE_NOW";
+
+/// The contents of `posts.toml`.
+#[derive(Debug, Deserialize)]
+pub struct Manifest {
+    pub site: Site,
+    #[serde(rename = "post")]
+    pub posts: Vec<PostMeta>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Site {
+    pub title: String,
+    pub author: String,
+    /// Absolute URL the site is deployed at, without a trailing slash.
+    pub url: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PostMeta {
+    /// Names the post's module in `src/pages`, its URL path and (in UpperCamelCase) its `Route`.
+    pub slug: String,
+    pub title: String,
+    pub date: Date,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Keeps the post out of listings and the feed, and its page behind `?preview`.
+    #[serde(default)]
+    pub draft: bool,
+    /// When the post last changed, if later than its newest revision.
+    pub updated: Option<Date>,
+    /// Notable edits since the post was published, newest first once loaded.
+    #[serde(rename = "revision", default)]
+    pub revisions: Vec<Revision>,
+    /// Adds a revision for every commit that touched the post's source after its date.
+    #[serde(default)]
+    pub git_history: bool,
+    /// The versions of the crates the post was written against, eg. `{ yew = "0.20" }`.
+    #[serde(default)]
+    pub crates: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Revision {
+    pub date: Date,
+    /// What changed, eg. `Updated the workflows for actions/checkout@v4`.
+    pub note: String,
+}
+
+impl Manifest {
+    /// Loads `posts.toml` from the site's root, listing posts newest first, and their revisions
+    /// newest first with those from git history included.
+    pub fn load(root: &Path) -> Result<Self, String> {
+        let path = root.join(MANIFEST);
+        let text = fs::read_to_string(&path)
+            .map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+        let mut manifest: Manifest =
+            toml::from_str(&text).map_err(|e| format!("{}: {e}", path.display()))?;
+
+        for post in &mut manifest.posts {
+            if post.git_history {
+                let commits = history::git_log(root, &post.source(root))?;
+                post.revisions.extend(
+                    commits
+                        .into_iter()
+                        .filter(|revision| revision.date > post.date),
+                );
+            }
+            post.revisions
+                .sort_by_key(|revision| Reverse(revision.date));
+        }
+
+        manifest.posts.sort_by_key(|post| Reverse(post.date));
+        Ok(manifest)
+    }
+
+    /// Whether any post takes revisions from git history, so the build depends on it.
+    pub fn uses_git_history(&self) -> bool {
+        self.posts.iter().any(|post| post.git_history)
+    }
+}
+
+impl Site {
+    /// The scheme and host the site is served from, eg. `https://example.github.io`.
+    pub fn origin(&self) -> &str {
+        let path = self.base_path();
+        let url = self.url.trim_end_matches('/');
+        &url[..url.len() - path.len()]
+    }
+
+    /// The absolute URL of `path` on the site, eg. `/blog1` becomes
+    /// `https://example.github.io/rust-site/blog1`, prefixed with `base_path` like every asset
+    /// and route.
+    pub fn absolute_url(&self, path: &str) -> String {
+        format!("{}{}{path}", self.origin(), self.base_path())
+    }
+
+    /// The path the site is served under, without a trailing slash: empty when it's deployed at
+    /// the root of its domain, `/rust-site` for `https://example.github.io/rust-site`.
+    pub fn base_path(&self) -> &str {
+        let without_scheme = self
+            .url
+            .split_once("://")
+            .map_or(&*self.url, |(_, rest)| rest);
+        without_scheme
+            .find('/')
+            .map_or("", |start| &without_scheme[start..])
+            .trim_end_matches('/')
+    }
+}
+
+impl PostMeta {
+    /// The post's path on the site, eg. `/blog1`.
+    pub fn path(&self) -> String {
+        format!("/{}", self.slug)
+    }
+
+    /// The name of the post's `Route` variant, eg. `Blog1`.
+    pub fn route_variant(&self) -> String {
+        let mut chars = self.slug.chars();
+        chars
+            .next()
+            .map(|first| first.to_uppercase().chain(chars).collect())
+            .unwrap_or_default()
+    }
+
+    /// Whether the post is finished and its date has come, as of `today`.
+    pub fn is_published(&self, today: Date) -> bool {
+        !self.draft && self.date <= today
+    }
+
+    /// When the post last changed after it was published, from `updated` or its revisions.
+    pub fn last_updated(&self) -> Option<Date> {
+        self.revisions
+            .iter()
+            .map(|revision| revision.date)
+            .chain(self.updated)
+            .max()
+            .filter(|&updated| updated > self.date)
+    }
+
+    /// The file defining the post's page component.
+    pub fn source(&self, root: &Path) -> PathBuf {
+        root.join(POSTS_DIR).join(format!("{}.rs", self.slug))
+    }
+}
+
+/// A calendar date, written `YYYY-MM-DD` in the manifest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Date {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl Date {
+    /// The day posts are published as of: `SITE_NOW` if it's set, otherwise today.
+    pub fn now() -> Result<Self, String> {
+        match env::var(NOW_VAR) {
+            Ok(value) => value.parse().map_err(|e| format!("{NOW_VAR}: {e}")),
+            Err(_) => Ok(Self::today()),
+        }
+    }
+
+    /// Today's date in UTC, from the system clock.
+    pub fn today() -> Self {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        Self::from_days((seconds / 86_400) as i64)
+    }
+
+    /// The date `days` days after 1970-01-01, using Howard Hinnant's `civil_from_days`.
+    fn from_days(days: i64) -> Self {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+        Date {
+            year: year as u16,
+            month: month as u8,
+            day: day as u8,
+        }
+    }
+}
+
+impl FromStr for Date {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid date `{s}`, expected YYYY-MM-DD");
+
+        let mut parts = s.splitn(3, '-');
+        let mut next = || parts.next().ok_or_else(invalid);
+        let year = next()?.parse().map_err(|_| invalid())?;
+        let month = next()?.parse().map_err(|_| invalid())?;
+        let day = next()?.parse().map_err(|_| invalid())?;
+
+        if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+            return Err(invalid());
+        }
+        Ok(Date { year, month, day })
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl<'de> Deserialize<'de> for Date {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        text.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn site(url: &str) -> Site {
+        Site {
+            title: String::new(),
+            author: String::new(),
+            url: url.to_string(),
+        }
+    }
+
+    #[test]
+    fn absolute_url_at_root() {
+        let site = site("https://supa.fish");
+        assert_eq!(site.base_path(), "");
+        assert_eq!(site.absolute_url("/"), "https://supa.fish/");
+        assert_eq!(site.absolute_url("/blog1"), "https://supa.fish/blog1");
+    }
+
+    #[test]
+    fn absolute_url_under_base_path() {
+        let site = site("https://example.github.io/rust-site/");
+        assert_eq!(site.base_path(), "/rust-site");
+        assert_eq!(site.origin(), "https://example.github.io");
+        assert_eq!(
+            site.absolute_url("/feed.xml"),
+            "https://example.github.io/rust-site/feed.xml"
+        );
+    }
+}
@@ -0,0 +1,41 @@
+//! Recorded results for the external links in posts, which the link checker reads instead of
+//! going out to the network.
+
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+/// Name of the file recording external link results, relative to the site's root.
+pub const LINKS: &str = "links.toml";
+
+/// The contents of `links.toml`, or of another file in the same format passed to the checker.
+#[derive(Debug, Default, Deserialize)]
+pub struct Links {
+    #[serde(rename = "link", default)]
+    pub links: Vec<LinkMeta>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LinkMeta {
+    /// The URL without its fragment, eg. `https://yew.rs/docs/more/deployment`.
+    pub url: String,
+    /// The HTTP status the URL last responded with.
+    pub status: u16,
+    /// The element ids on the page, when fragments pointing into it should be checked too.
+    pub anchors: Option<Vec<String>>,
+}
+
+impl Links {
+    /// Loads a `links.toml`-style file from `path`.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+        toml::from_str(&text).map_err(|e| format!("{}: {e}", path.display()))
+    }
+
+    /// What's recorded for `url`, which shouldn't include a fragment.
+    pub fn get(&self, url: &str) -> Option<&LinkMeta> {
+        self.links.iter().find(|link| link.url == url)
+    }
+}
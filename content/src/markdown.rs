@@ -0,0 +1,446 @@
+//! Finds the Markdown written in posts with `markdown!`, which the build script renders to
+//! `html!` and the link checker reads links from, and picks out the parts of it that render to
+//! components: footnotes, quote attributions and code blocks.
+
+use std::collections::{BTreeMap, VecDeque};
+
+use pulldown_cmark::{CodeBlockKind, Event, Options, Parser, Tag, TagEnd};
+
+use crate::source;
+
+const MACRO: &str = "markdown!(";
+
+/// What starts the last paragraph of a blockquote when it says who's being quoted.
+const ATTRIBUTION_DASHES: [&str; 2] = ["—", "--"];
+
+/// The Markdown passed to one `markdown!`.
+pub struct Block {
+    /// The string literal as written, which the macro's arm has to repeat exactly to match it.
+    pub literal: String,
+    /// The Markdown itself, without the indentation its lines share.
+    pub markdown: String,
+    /// 1-based line the literal starts on.
+    pub line: usize,
+}
+
+/// Every `markdown!` in `source`, eg. `markdown!(r#"> [!TIP]\n> Run `trunk serve`"#)`.
+pub fn find(source: &str) -> Vec<Block> {
+    let literals = source::literals(source);
+    source
+        .match_indices(MACRO)
+        .filter_map(|(start, _)| {
+            let args = start + MACRO.len();
+            let literal = literals
+                .iter()
+                .find(|literal| literal.span.start >= args)
+                .filter(|literal| source[args..literal.span.start].trim().is_empty())?;
+            Some(Block {
+                literal: source[literal.span.clone()].to_string(),
+                markdown: dedent(&literal.value),
+                line: literal.line,
+            })
+        })
+        .collect()
+}
+
+/// The Markdown extensions posts can use: GitHub's alerts (`> [!NOTE]`), footnotes (`[^1]`) and
+/// strikethrough.
+pub fn options() -> Options {
+    Options::ENABLE_GFM | Options::ENABLE_FOOTNOTES | Options::ENABLE_STRIKETHROUGH
+}
+
+/// Every link and image URL in `markdown`, with the 0-based line it's on.
+pub fn links(markdown: &str) -> Vec<(String, usize)> {
+    Parser::new_ext(markdown, options())
+        .into_offset_iter()
+        .filter_map(|(event, range)| match event {
+            Event::Start(Tag::Link { dest_url, .. } | Tag::Image { dest_url, .. }) => Some((
+                dest_url.to_string(),
+                markdown[..range.start].matches('\n').count(),
+            )),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Splits the events of `markdown` into those of its body and those inside each footnote
+/// definition, by label. References to the footnotes stay in the body, where they're made.
+pub fn footnotes(markdown: &str) -> (Vec<Event<'_>>, BTreeMap<String, Vec<Event<'_>>>) {
+    let mut definitions = BTreeMap::new();
+    let mut definition: Option<(String, Vec<Event>)> = None;
+    let mut body = Vec::new();
+
+    for event in Parser::new_ext(markdown, options()) {
+        match event {
+            Event::Start(Tag::FootnoteDefinition(label)) => {
+                definition = Some((label.to_string(), Vec::new()));
+            }
+            Event::End(TagEnd::FootnoteDefinition) => {
+                if let Some((label, events)) = definition.take() {
+                    definitions.insert(label, events);
+                }
+            }
+            event => match &mut definition {
+                Some((_, events)) => events.push(event),
+                None => body.push(event),
+            },
+        }
+    }
+    (body, definitions)
+}
+
+/// Who a blockquote quotes, from the paragraph ending it that starts with a dash, eg.
+/// `> — [Taxus baccata, Wikipedia](https://en.wikipedia.org/wiki/Taxus_baccata)`.
+pub struct Attribution {
+    pub text: String,
+    /// Where the quote is from, if the attribution is a link.
+    pub source: Option<String>,
+}
+
+/// Takes attribution paragraphs out of the blockquotes in `events`, returning what's left and the
+/// attribution of each blockquote that isn't an alert, in the order they start.
+pub fn attributions(events: Vec<Event>) -> (Vec<Event>, VecDeque<Option<Attribution>>) {
+    // Where each blockquote that's still open starts, and its attribution paragraph's span.
+    let mut open = Vec::new();
+    let mut found = BTreeMap::new();
+    let mut removed = Vec::new();
+
+    for (index, event) in events.iter().enumerate() {
+        match event {
+            Event::Start(Tag::BlockQuote(None)) => open.push(index),
+            Event::End(TagEnd::BlockQuote(None)) => {
+                let Some(start) = open.pop() else { continue };
+                if !matches!(events[index - 1], Event::End(TagEnd::Paragraph)) {
+                    continue;
+                }
+                let Some(first) = events[..index - 1]
+                    .iter()
+                    .rposition(|event| matches!(event, Event::Start(Tag::Paragraph)))
+                else {
+                    continue;
+                };
+                // A quote that's only an attribution is still a quote.
+                if first == start + 1 {
+                    continue;
+                }
+                if let Some(attribution) = attribution(&events[first + 1..index - 1]) {
+                    found.insert(start, attribution);
+                    removed.push(first..index);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut quotes = VecDeque::new();
+    let mut kept = Vec::new();
+    for (index, event) in events.into_iter().enumerate() {
+        if matches!(event, Event::Start(Tag::BlockQuote(None))) {
+            quotes.push_back(found.remove(&index));
+        }
+        if !removed.iter().any(|range| range.contains(&index)) {
+            kept.push(event);
+        }
+    }
+    (kept, quotes)
+}
+
+/// The attribution in a paragraph's `events`, if it starts with a dash.
+fn attribution(events: &[Event]) -> Option<Attribution> {
+    let Some(Event::Text(first)) = events.first() else {
+        return None;
+    };
+    let dash = ATTRIBUTION_DASHES
+        .iter()
+        .find(|dash| first.starts_with(**dash))?;
+
+    let mut text = String::new();
+    let mut sources = Vec::new();
+    for event in events {
+        match event {
+            Event::Text(part) | Event::Code(part) => text.push_str(part),
+            Event::SoftBreak => text.push(' '),
+            Event::Start(Tag::Link { dest_url, .. }) => sources.push(dest_url.to_string()),
+            _ => {}
+        }
+    }
+    Some(Attribution {
+        text: text[dash.len()..].trim().to_string(),
+        source: if sources.len() == 1 {
+            sources.pop()
+        } else {
+            None
+        },
+    })
+}
+
+/// A fenced code block in some Markdown.
+pub struct CodeBlock {
+    /// What follows the opening fence, split on commas, eg. `["rust", "runnable"]`.
+    pub attributes: Vec<String>,
+    pub code: String,
+    /// 0-based line the opening fence is on.
+    pub line: usize,
+}
+
+impl CodeBlock {
+    /// Whether the block is Rust that should compile, ie. it's tagged `rust` and not `ignore`.
+    pub fn is_checked(&self) -> bool {
+        self.attributes
+            .first()
+            .is_some_and(|language| language == "rust")
+            && !self.has("ignore")
+    }
+
+    pub fn has(&self, attribute: &str) -> bool {
+        self.attributes
+            .iter()
+            .skip(1)
+            .any(|other| other == attribute)
+    }
+}
+
+/// Every fenced code block in `markdown`.
+pub fn code_blocks(markdown: &str) -> Vec<CodeBlock> {
+    let mut blocks = Vec::new();
+    let mut current: Option<CodeBlock> = None;
+
+    for (event, range) in Parser::new_ext(markdown, options()).into_offset_iter() {
+        match event {
+            Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(info))) => {
+                current = Some(CodeBlock {
+                    attributes: info
+                        .split(',')
+                        .map(|part| part.trim().to_string())
+                        .collect(),
+                    code: String::new(),
+                    line: markdown[..range.start].matches('\n').count(),
+                });
+            }
+            Event::Text(text) => {
+                if let Some(block) = &mut current {
+                    block.code.push_str(&text);
+                }
+            }
+            Event::End(TagEnd::CodeBlock) => blocks.extend(current.take()),
+            _ => {}
+        }
+    }
+    blocks
+}
+
+/// `code` without the setup lines a Rust example hides the way rustdoc does, by starting them
+/// with `# `. A line starting with `##` shows with one `#`.
+pub fn shown_lines(code: &str) -> String {
+    code.lines()
+        .filter_map(|line| {
+            let trimmed = line.trim_start();
+            let indent = &line[..line.len() - trimmed.len()];
+            if let Some(rest) = trimmed.strip_prefix("##") {
+                Some(format!("{indent}#{rest}\n"))
+            } else if trimmed == "#" || trimmed.starts_with("# ") {
+                None
+            } else {
+                Some(format!("{line}\n"))
+            }
+        })
+        .collect()
+}
+
+/// `code` as it compiles, with hidden lines' `# ` removed.
+pub fn compiled_lines(code: &str) -> String {
+    code.lines()
+        .map(|line| {
+            let trimmed = line.trim_start();
+            let indent = &line[..line.len() - trimmed.len()];
+            match trimmed.strip_prefix('#') {
+                Some("") => "\n".to_string(),
+                Some(rest) if rest.starts_with(['#', ' ']) => {
+                    format!("{indent}{}\n", rest.strip_prefix(' ').unwrap_or(rest))
+                }
+                _ => format!("{line}\n"),
+            }
+        })
+        .collect()
+}
+
+/// Removes the indentation every non-blank line shares, which Markdown would otherwise read as a
+/// code block. Lines stay where they were, so line numbers within the literal still hold.
+fn dedent(text: &str) -> String {
+    let indent = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start_matches([' ', '\t']).len())
+        .min()
+        .unwrap_or(0);
+
+    let lines: Vec<&str> = text
+        .lines()
+        .map(|line| line.get(indent..).unwrap_or_default())
+        .collect();
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block(markdown: &str) -> CodeBlock {
+        let mut blocks = code_blocks(markdown);
+        assert_eq!(blocks.len(), 1);
+        blocks.remove(0)
+    }
+
+    #[test]
+    fn code_block_attributes() {
+        let runnable = block("```rust,runnable\nfn main() {}\n```\n");
+        assert_eq!(runnable.attributes, ["rust", "runnable"]);
+        assert!(runnable.is_checked());
+        assert!(runnable.has("runnable"));
+
+        let ignored = block("```rust, ignore\nfn main() {\n```\n");
+        assert_eq!(ignored.attributes, ["rust", "ignore"]);
+        assert!(!ignored.is_checked());
+
+        assert!(!block("```toml\n[package]\n```\n").is_checked());
+        assert!(!block("```\nplain\n```\n").is_checked());
+        // Only attributes after the language count.
+        assert!(!block("```runnable\nx\n```\n").has("runnable"));
+    }
+
+    #[test]
+    fn code_blocks_keep_their_code_and_line() {
+        let blocks = code_blocks("Intro\n\n```rust\nfn main() {}\n```\n\n    indented\n");
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].code, "fn main() {}\n");
+        assert_eq!(blocks[0].line, 2);
+    }
+
+    #[test]
+    fn shown_lines_hide_setup() {
+        let code =
+            "# use std::fmt;\n#\nfn main() {\n    # let hidden = 1;\n    println!(\"hi\");\n}\n";
+        assert_eq!(shown_lines(code), "fn main() {\n    println!(\"hi\");\n}\n");
+    }
+
+    #[test]
+    fn shown_lines_unescape_and_keep_attributes() {
+        let code = "## not hidden\n    ##[derive(Debug)]\n#[derive(Debug)]\n#![allow(unused)]\n";
+        assert_eq!(
+            shown_lines(code),
+            "# not hidden\n    #[derive(Debug)]\n#[derive(Debug)]\n#![allow(unused)]\n"
+        );
+    }
+
+    #[test]
+    fn compiled_lines_keep_setup() {
+        let code = "# use std::fmt;\n#\nfn main() {\n    # let hidden = 1;\n}\n";
+        assert_eq!(
+            compiled_lines(code),
+            "use std::fmt;\n\nfn main() {\n    let hidden = 1;\n}\n"
+        );
+    }
+
+    #[test]
+    fn compiled_lines_unescape_and_keep_attributes() {
+        let code = "## not hidden\n#[derive(Debug)]\n#![allow(unused)]\n";
+        assert_eq!(
+            compiled_lines(code),
+            "# not hidden\n#[derive(Debug)]\n#![allow(unused)]\n"
+        );
+    }
+
+    /// A quote's attribution text and source, if it has one.
+    type Found = Option<(String, Option<String>)>;
+
+    /// The attribution of each quote in `markdown`, and the text left in the quotes.
+    fn quotes(markdown: &str) -> (Vec<Found>, String) {
+        let events: Vec<Event> = Parser::new_ext(markdown, options()).collect();
+        let (kept, attributions) = attributions(events);
+        let text = kept
+            .iter()
+            .filter_map(|event| match event {
+                Event::Text(text) => Some(&**text),
+                _ => None,
+            })
+            .collect();
+        let attributions = attributions
+            .into_iter()
+            .map(|found| found.map(|Attribution { text, source }| (text, source)))
+            .collect();
+        (attributions, text)
+    }
+
+    #[test]
+    fn attribution_with_a_source() {
+        let (attributions, text) =
+            quotes("> Yews live for millennia.\n>\n> — [Taxus baccata, Wikipedia](https://en.wikipedia.org/wiki/Taxus_baccata)\n");
+        assert_eq!(
+            attributions,
+            [Some((
+                "Taxus baccata, Wikipedia".to_string(),
+                Some("https://en.wikipedia.org/wiki/Taxus_baccata".to_string())
+            ))]
+        );
+        assert_eq!(text, "Yews live for millennia.");
+    }
+
+    #[test]
+    fn attribution_without_a_single_source() {
+        let (attributions, _) = quotes("> Quoted.\n>\n> -- Someone\n");
+        assert_eq!(attributions, [Some(("Someone".to_string(), None))]);
+
+        let (attributions, _) =
+            quotes("> Quoted.\n>\n> — [One](https://a.example) and [two](https://b.example)\n");
+        assert_eq!(attributions, [Some(("One and two".to_string(), None))]);
+    }
+
+    #[test]
+    fn quotes_without_attributions() {
+        // No dash, and a quote that's nothing but an attribution.
+        let (attributions, text) = quotes("> Just a quote.\n\n> — Only a dash\n");
+        assert_eq!(attributions, [None, None]);
+        assert_eq!(text, "Just a quote.— Only a dash");
+    }
+
+    #[test]
+    fn alerts_have_no_attribution() {
+        let (attributions, text) = quotes(
+            "> [!NOTE]\n> A note.\n>\n> — Not an attribution\n\n> Quoted.\n>\n> — Someone\n",
+        );
+        assert_eq!(attributions, [Some(("Someone".to_string(), None))]);
+        assert_eq!(text, "A note.— Not an attributionQuoted.");
+    }
+
+    #[test]
+    fn footnotes_leave_references_in_the_body() {
+        let (body, definitions) =
+            footnotes("Yew[^yew] and Trunk[^trunk].\n\n[^yew]: A tree.\n[^trunk]: A bundler.\n");
+
+        let references: Vec<&str> = body
+            .iter()
+            .filter_map(|event| match event {
+                Event::FootnoteReference(label) => Some(&**label),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(references, ["yew", "trunk"]);
+        assert!(!body.iter().any(|event| matches!(
+            event,
+            Event::Text(text) if text.contains("tree") || text.contains("bundler")
+        )));
+
+        let text = |label: &str| -> String {
+            definitions[label]
+                .iter()
+                .filter_map(|event| match event {
+                    Event::Text(text) => Some(&**text),
+                    _ => None,
+                })
+                .collect()
+        };
+        assert_eq!(definitions.len(), 2);
+        assert_eq!(text("yew"), "A tree.");
+        assert_eq!(text("trunk"), "A bundler.");
+    }
+}
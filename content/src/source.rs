@@ -0,0 +1,120 @@
+//! Lightweight analysis of the string literals in a post's `html!` body.
+
+use std::ops::Range;
+
+/// Counts the words in the text nodes of an `html!` body, ie. string literals that aren't the
+/// value of an attribute.
+pub fn count_words(source: &str) -> usize {
+    literals(source)
+        .iter()
+        .filter(|literal| literal.attribute.is_none())
+        .flat_map(|literal| literal.value.split_whitespace())
+        .filter(|word| word.chars().any(char::is_alphanumeric))
+        .count()
+}
+
+/// A string literal found in Rust source.
+pub struct Literal {
+    pub value: String,
+    /// 1-based line the literal starts on.
+    pub line: usize,
+    /// Where the literal is in the source, quotes (and any `r#`) included.
+    pub span: Range<usize>,
+    /// The attribute the literal is assigned to (`href` for `href="..."`), or `None` for text.
+    pub attribute: Option<String>,
+}
+
+/// Every string literal in `source`, in order.
+pub fn literals(source: &str) -> Vec<Literal> {
+    let bytes = source.as_bytes();
+    let mut literals = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let is_raw = bytes[i] == b'r'
+            && matches!(bytes.get(i + 1), Some(b'#') | Some(b'"'))
+            && !(i > 0 && (bytes[i - 1].is_ascii_alphanumeric() || bytes[i - 1] == b'_'));
+        if bytes[i] != b'"' && !is_raw {
+            i += 1;
+            continue;
+        }
+
+        let (value, end) = if is_raw {
+            raw_literal(source, i)
+        } else {
+            quoted_literal(source, i)
+        };
+        literals.push(Literal {
+            value,
+            line: source[..i].matches('\n').count() + 1,
+            span: i..end,
+            attribute: attribute_name(&source[..i]),
+        });
+        i = end;
+    }
+
+    literals
+}
+
+/// The name of the attribute being assigned at the end of `before`, if it ends with `name=`.
+fn attribute_name(before: &str) -> Option<String> {
+    let before = before.trim_end().strip_suffix('=')?.trim_end();
+    let name_len: usize = before
+        .chars()
+        .rev()
+        .take_while(|&c| c.is_alphanumeric() || c == '-' || c == '_')
+        .map(char::len_utf8)
+        .sum();
+    let start = before.len() - name_len;
+    Some(before[start..].to_string())
+}
+
+/// Parses `"..."` starting at `start`, returning its contents and the index after the closing quote.
+fn quoted_literal(source: &str, start: usize) -> (String, usize) {
+    let mut literal = String::new();
+    let mut chars = source[start + 1..].char_indices();
+
+    while let Some((offset, c)) = chars.next() {
+        match c {
+            '\\' => match chars.next().map(|(_, escaped)| escaped) {
+                Some('n') => literal.push('\n'),
+                Some('t') => literal.push('\t'),
+                Some('r') => literal.push('\r'),
+                Some('0') => literal.push('\0'),
+                // A line continuation, which skips the line break and the next line's indent.
+                Some('\n') => {
+                    while chars.clone().next().is_some_and(|(_, c)| c.is_whitespace()) {
+                        chars.next();
+                    }
+                }
+                Some(escaped) => literal.push(escaped),
+                None => {}
+            },
+            '"' => return (literal, start + 1 + offset + 1),
+            _ => literal.push(c),
+        }
+    }
+
+    (literal, source.len())
+}
+
+/// Parses `r#"..."#` starting at `start`, returning its contents and the index after the literal.
+fn raw_literal(source: &str, start: usize) -> (String, usize) {
+    let hashes = source[start + 1..]
+        .chars()
+        .take_while(|&c| c == '#')
+        .count();
+    let open = start + 1 + hashes;
+    if source.as_bytes().get(open) != Some(&b'"') {
+        return (String::new(), start + 1);
+    }
+
+    let terminator = format!("\"{}", "#".repeat(hashes));
+    match source[open + 1..].find(&terminator) {
+        Some(len) => (
+            source[open + 1..open + 1 + len].to_string(),
+            open + 1 + len + terminator.len(),
+        ),
+        None => (String::new(), source.len()),
+    }
+}
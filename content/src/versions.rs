@@ -0,0 +1,142 @@
+//! The crate versions the site builds with, read from `Cargo.lock`, for comparing with the
+//! versions posts were written against.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+/// Name of the lock file, relative to the site's root.
+pub const LOCK_FILE: &str = "Cargo.lock";
+
+/// The site's own package, whose dependencies are the versions readers would see.
+const SITE_PACKAGE: &str = "rust-site";
+
+#[derive(Deserialize)]
+struct Lock {
+    #[serde(rename = "package", default)]
+    packages: Vec<Package>,
+}
+
+#[derive(Deserialize)]
+struct Package {
+    name: String,
+    version: String,
+    #[serde(default)]
+    dependencies: Vec<String>,
+}
+
+/// The version of each of the site's direct dependencies, by crate name.
+pub fn locked(root: &Path) -> Result<BTreeMap<String, String>, String> {
+    let path = root.join(LOCK_FILE);
+    let text =
+        fs::read_to_string(&path).map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+    from_lock(&text).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// [`locked`], from the text of a `Cargo.lock`.
+fn from_lock(text: &str) -> Result<BTreeMap<String, String>, String> {
+    let lock: Lock = toml::from_str(text).map_err(|e| e.to_string())?;
+
+    let site = lock
+        .packages
+        .iter()
+        .find(|package| package.name == SITE_PACKAGE)
+        .ok_or_else(|| format!("no {SITE_PACKAGE} package"))?;
+
+    // Dependencies are listed as `name`, or as `name version` when several versions are locked.
+    let mut versions = BTreeMap::new();
+    for dependency in &site.dependencies {
+        let mut parts = dependency.split_whitespace();
+        let Some(name) = parts.next() else { continue };
+        let version = match parts.next() {
+            Some(version) => Some(version.to_string()),
+            None => lock
+                .packages
+                .iter()
+                .find(|package| package.name == name)
+                .map(|package| package.version.clone()),
+        };
+        if let Some(version) = version {
+            versions.insert(name.to_string(), version);
+        }
+    }
+    Ok(versions)
+}
+
+/// Whether `current` is semver-compatible with `written_for`, which may leave out components,
+/// eg. `0.20` is compatible with `0.20.3` but not `0.21.0`, and `1` with `1.4.0`.
+pub fn is_compatible(written_for: &str, current: &str) -> bool {
+    let parse = |version: &str| -> Vec<u64> {
+        version
+            .split(['-', '+'])
+            .next()
+            .unwrap_or_default()
+            .split('.')
+            .map(|part| part.parse().unwrap_or(0))
+            .collect()
+    };
+    let written_for = parse(written_for);
+    let current = parse(current);
+
+    // Cargo treats everything up to and including the first non-zero component as breaking.
+    let significant = written_for
+        .iter()
+        .position(|&part| part != 0)
+        .map_or(written_for.len(), |i| i + 1);
+    written_for[..significant] == current[..significant.min(current.len())]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compatible_within_a_minor_version() {
+        assert!(is_compatible("0.20", "0.20.3"));
+        assert!(!is_compatible("0.20", "0.21.0"));
+    }
+
+    #[test]
+    fn compatible_within_a_major_version() {
+        assert!(is_compatible("1", "1.4.0"));
+        assert!(!is_compatible("1", "2.0.0"));
+    }
+
+    #[test]
+    fn every_patch_breaks_before_0_1() {
+        assert!(!is_compatible("0.0.3", "0.0.4"));
+        assert!(is_compatible("0.0.3", "0.0.3"));
+    }
+
+    #[test]
+    fn locked_picks_the_version_the_site_depends_on() {
+        let lock = r#"
+            [[package]]
+            name = "rust-site"
+            version = "0.1.0"
+            dependencies = ["gloo", "yew 0.20.0"]
+
+            [[package]]
+            name = "gloo"
+            version = "0.8.0"
+
+            [[package]]
+            name = "yew"
+            version = "0.19.3"
+
+            [[package]]
+            name = "yew"
+            version = "0.20.0"
+        "#;
+        let locked = from_lock(lock).unwrap();
+        assert_eq!(locked["gloo"], "0.8.0");
+        assert_eq!(locked["yew"], "0.20.0");
+    }
+
+    #[test]
+    fn locked_needs_the_site_package() {
+        assert!(from_lock("[[package]]\nname = \"yew\"\nversion = \"0.20.0\"\n").is_err());
+    }
+}
@@ -0,0 +1,37 @@
+//! Metadata for the YouTube videos embedded in posts.
+
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+/// Name of the file listing every embedded video, relative to the site's root.
+pub const VIDEOS: &str = "videos.toml";
+
+/// The contents of `videos.toml`.
+#[derive(Debug, Deserialize)]
+pub struct Videos {
+    #[serde(rename = "video", default)]
+    pub videos: Vec<VideoMeta>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct VideoMeta {
+    /// The id in the video's YouTube URL, eg. `P4LMfkFLRsI`.
+    pub id: String,
+    pub title: String,
+    pub channel: String,
+    /// An image under `img/` shown until the video plays, eg. `img/videos/rust-and-wasm.jpg`.
+    /// Required, so an embed never falls back to a blank card.
+    pub thumbnail: String,
+}
+
+impl Videos {
+    /// Loads `videos.toml` from the site's root.
+    pub fn load(root: &Path) -> Result<Self, String> {
+        let path = root.join(VIDEOS);
+        let text = fs::read_to_string(&path)
+            .map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+        toml::from_str(&text).map_err(|e| format!("{}: {e}", path.display()))
+    }
+}
@@ -0,0 +1,52 @@
+use yew::html::IntoPropValue;
+
+/// An image under `img/`, with the intrinsic size the build script read from the file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ImageAsset {
+    /// Path relative to the site root, eg. `img/blog2/kronk.gif`.
+    pub path: &'static str,
+    /// Where the image is served, with the site's base path and a hash of its contents, eg.
+    /// `/img/blog2/kronk-1f0a9c3e5b7d2468.gif`.
+    pub url: &'static str,
+    pub width: u32,
+    pub height: u32,
+    /// Resized copies in more compact formats, most compact first. Empty for GIFs.
+    pub sources: &'static [ImageSource],
+}
+
+/// A GIF under `img/`, with the video the build converts it to. `asset!` gives one of these for
+/// every GIF, so only GIFs can be passed where one is expected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AnimatedAsset {
+    pub image: ImageAsset,
+    pub animation: Animation,
+}
+
+/// Lets a GIF be shown as a still `Image` too.
+impl IntoPropValue<ImageAsset> for AnimatedAsset {
+    fn into_prop_value(self) -> ImageAsset {
+        self.image
+    }
+}
+
+/// Every variant of an image in one format, as listed in a `<source>`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ImageSource {
+    pub mime: &'static str,
+    /// Comma-separated `url width` pairs, eg. `/img/blog2/app-1f0a9c3e5b7d2468-480.avif 480w, ...`.
+    pub srcset: &'static str,
+}
+
+/// The muted, looping video a GIF is served as instead.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Animation {
+    pub video: &'static str,
+    /// The video's `type`, for `<source>` and `canPlayType`.
+    pub mime: &'static str,
+    /// A still of the first frame, shown until the video plays.
+    pub poster: &'static str,
+}
+
+// Generated by the build script: one constant per image (an `AnimatedAsset` for GIFs), one module
+// per directory, and the `asset!` macro.
+include!(concat!(env!("OUT_DIR"), "/assets.rs"));
@@ -0,0 +1,194 @@
+use stylist::css;
+use web_sys::HtmlMediaElement;
+use yew::prelude::*;
+
+use crate::assets::AnimatedAsset;
+use crate::hooks::use_theme;
+
+fn prefers_reduced_motion() -> bool {
+    gloo_utils::window()
+        .match_media("(prefers-reduced-motion: reduce)")
+        .ok()
+        .flatten()
+        .is_some_and(|query| query.matches())
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    /// A GIF, which the build converts to a video.
+    pub src: AnimatedAsset,
+    /// What the animation shows, for readers who can't see it.
+    pub alt: AttrValue,
+    /// Cap the width for small animations that look blurry at full width.
+    #[prop_or_default]
+    pub narrow: bool,
+    /// Optional caption, rendered below the animation.
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// A GIF played as the muted, looping video the build converted it to, with a play/pause button.
+/// It starts paused on its first frame for readers who prefer reduced motion.
+///
+/// Browsers that can't play the video fall back to the GIF, shown only while playing.
+#[function_component(AnimatedImage)]
+pub fn component(props: &Props) -> Html {
+    let theme = use_theme().theme;
+    let animation = props.src.animation;
+
+    let video = use_node_ref();
+    let playing = use_state_eq(|| false);
+    let can_play = use_state_eq(|| true);
+
+    {
+        let video = video.clone();
+        let playing = playing.clone();
+        let can_play = can_play.clone();
+        use_effect_with_deps(
+            move |_| {
+                let supported = video
+                    .cast::<HtmlMediaElement>()
+                    .is_some_and(|video| !video.can_play_type(animation.mime).is_empty());
+                can_play.set(supported);
+
+                if !prefers_reduced_motion() {
+                    match video.cast::<HtmlMediaElement>().filter(|_| supported) {
+                        Some(video) => {
+                            // The attribute only sets the default, which autoplay policies
+                            // don't look at.
+                            video.set_muted(true);
+                            let _ = video.play();
+                        }
+                        None => playing.set(true),
+                    }
+                }
+            },
+            animation,
+        );
+    }
+
+    let onclick = {
+        let video = video.clone();
+        let playing = playing.clone();
+        let can_play = *can_play;
+        Callback::from(move |_| match video.cast::<HtmlMediaElement>() {
+            Some(video) if can_play && video.paused() => {
+                video.set_muted(true);
+                let _ = video.play();
+            }
+            Some(video) if can_play => video.pause().unwrap_or_default(),
+            _ => playing.set(!*playing),
+        })
+    };
+    let onplay = {
+        let playing = playing.clone();
+        Callback::from(move |_| playing.set(true))
+    };
+    let onpause = {
+        let playing = playing.clone();
+        Callback::from(move |_| playing.set(false))
+    };
+
+    let style = css!(
+        r#"
+            position: relative;
+            display: inline-block;
+            max-width: min(100%, 800px);
+            vertical-align: top;
+
+            &.narrow {
+                max-width: min(100%, 500px);
+            }
+
+            video,
+            img {
+                display: block;
+                width: 100%;
+                max-width: 100%;
+                height: auto;
+            }
+
+            button {
+                position: absolute;
+                left: ${space_sm};
+                bottom: ${space_sm};
+                min-width: 44px;
+                min-height: 44px;
+                border: 2px solid ${border};
+                border-radius: ${radius};
+                background-color: ${background};
+                color: ${text};
+                font-size: 1.25em;
+                cursor: pointer;
+                opacity: 0.9;
+            }
+
+            button:focus-visible {
+                outline: 3px solid ${accent};
+                outline-offset: 2px;
+            }
+        "#,
+        space_sm = theme.spacing.sm,
+        border = theme.colors.border,
+        radius = theme.radii.round,
+        background = theme.colors.background,
+        text = theme.colors.text,
+        accent = theme.colors.accent,
+    );
+
+    let width = props.src.image.width.to_string();
+    let height = props.src.image.height.to_string();
+    let media = if *can_play {
+        html! {
+            <video
+                ref={video}
+                muted=true
+                loop=true
+                playsinline=true
+                preload="metadata"
+                poster={animation.poster}
+                width={width}
+                height={height}
+                aria-label={props.alt.clone()}
+                {onplay}
+                {onpause}
+            >
+                <source src={animation.video} type={animation.mime} />
+            </video>
+        }
+    } else {
+        let src = if *playing {
+            props.src.image.url
+        } else {
+            animation.poster
+        };
+        html! {
+            <img {src} alt={props.alt.clone()} {width} {height} decoding="async" />
+        }
+    };
+
+    let label = if *playing {
+        "Pause animation"
+    } else {
+        "Play animation"
+    };
+    let media = html! {
+        <div class={classes!(style, props.narrow.then_some("narrow"))}>
+            { media }
+            <button type="button" {onclick} aria-label={label} title={label}>
+                { if *playing { "⏸" } else { "▶" } }
+            </button>
+        </div>
+    };
+
+    if props.children.is_empty() {
+        media
+    } else {
+        html! {
+            <figure>
+                { media }
+                <figcaption>{ for props.children.iter() }</figcaption>
+            </figure>
+        }
+    }
+}
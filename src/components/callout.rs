@@ -0,0 +1,115 @@
+use stylist::css;
+use yew::prelude::*;
+
+use crate::hooks::use_theme;
+use crate::theme::Colors;
+
+// Not every kind is necessarily in use by a post at any one time.
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CalloutKind {
+    /// Background worth knowing.
+    Note,
+    /// A shortcut or better way of doing something.
+    Tip,
+    /// Something that will go wrong if it's skipped.
+    Warning,
+    /// Something that can lose data or break a deployment.
+    Danger,
+}
+
+impl CalloutKind {
+    fn label(self) -> &'static str {
+        match self {
+            CalloutKind::Note => "Note",
+            CalloutKind::Tip => "Tip",
+            CalloutKind::Warning => "Warning",
+            CalloutKind::Danger => "Danger",
+        }
+    }
+
+    fn icon(self) -> &'static str {
+        match self {
+            CalloutKind::Note => "ℹ️",
+            CalloutKind::Tip => "💡",
+            CalloutKind::Warning => "⚠️",
+            CalloutKind::Danger => "⛔",
+        }
+    }
+
+    fn color(self, colors: &Colors) -> &'static str {
+        match self {
+            CalloutKind::Note => colors.note,
+            CalloutKind::Tip => colors.tip,
+            CalloutKind::Warning => colors.warning,
+            CalloutKind::Danger => colors.danger,
+        }
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub kind: CalloutKind,
+    /// Replaces the kind's name as the heading, eg. "Change the cname".
+    #[prop_or_default]
+    pub title: Option<AttrValue>,
+    pub children: Children,
+}
+
+/// A boxed aside that makes a note, tip, warning or danger stand out from the prose around it.
+/// In `markdown!`, written as a GitHub-style alert: `> [!NOTE]`, `> [!TIP]`, `> [!WARNING]` (or
+/// `> [!IMPORTANT]`) and `> [!CAUTION]` for danger.
+#[function_component(Callout)]
+pub fn component(props: &Props) -> Html {
+    let theme = use_theme().theme;
+    let style = css!(
+        r#"
+            box-sizing: border-box;
+            max-width: min(100% - 2 * ${space_md}, 800px);
+            margin: ${space_md} auto;
+            padding: ${space_sm} ${space_md};
+            border: 2px solid ${border};
+            border-left: 6px solid ${color};
+            border-radius: ${radius};
+            background-color: ${code_background};
+            color: ${text};
+            text-align: left;
+            line-height: 1.5;
+
+            .callout-title {
+                display: flex;
+                align-items: center;
+                gap: ${space_sm};
+                color: ${color};
+                font-weight: bold;
+            }
+
+            .callout-body p {
+                margin: ${space_sm} 0 0;
+                padding: 0;
+            }
+        "#,
+        space_sm = theme.spacing.sm,
+        space_md = theme.spacing.md,
+        border = theme.colors.border,
+        color = props.kind.color(&theme.colors),
+        radius = theme.radii.sm,
+        code_background = theme.colors.code_background,
+        text = theme.colors.text,
+    );
+
+    let title = props
+        .title
+        .clone()
+        .unwrap_or_else(|| props.kind.label().into());
+
+    html! {
+        <aside class={style} role="note" aria-label={props.kind.label()}>
+            <div class="callout-title">
+                <span aria-hidden="true">{ props.kind.icon() }</span>
+                { title }
+            </div>
+            <div class="callout-body">{ for props.children.iter() }</div>
+        </aside>
+    }
+}
@@ -0,0 +1,103 @@
+use stylist::css;
+use yew::prelude::*;
+
+use crate::hooks::use_theme;
+use crate::snippets::Snippet;
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    /// Usually from `include_file!`.
+    pub snippet: Snippet,
+}
+
+/// A highlighted code block showing a file from the repo, titled with its path and numbered with
+/// its real line numbers.
+#[function_component(CodeSnippet)]
+pub fn component(props: &Props) -> Html {
+    let theme = use_theme().theme;
+    let snippet = props.snippet;
+
+    let style = css!(
+        r#"
+            box-sizing: border-box;
+            width: min(100% - 2 * ${space_md}, 800px);
+            margin: ${space_sm} 0;
+            border: 2px solid ${border};
+            border-radius: ${radius};
+            background-color: ${code_background};
+            font-family: ${mono};
+            text-align: left;
+
+            .snippet-title {
+                padding: ${space_xs} ${space_sm};
+                border-bottom: 2px solid ${border};
+                font-weight: bold;
+            }
+
+            pre {
+                margin: 0;
+                padding: ${space_sm};
+                overflow-x: auto;
+                font-family: inherit;
+            }
+
+            .line-number {
+                display: inline-block;
+                min-width: 2.5em;
+                padding-right: ${space_sm};
+                color: ${muted};
+                text-align: right;
+                user-select: none;
+            }
+
+            .hl-comment {
+                color: ${muted};
+                font-style: italic;
+            }
+
+            .hl-string {
+                color: ${link};
+            }
+
+            .hl-constant,
+            .hl-name {
+                color: ${link_visited};
+            }
+
+            .hl-keyword {
+                color: ${accent};
+            }
+        "#,
+        space_xs = theme.spacing.xs,
+        space_sm = theme.spacing.sm,
+        space_md = theme.spacing.md,
+        border = theme.colors.border,
+        radius = theme.radii.sm,
+        code_background = theme.colors.code_background,
+        mono = theme.fonts.mono,
+        muted = theme.colors.muted,
+        link = theme.colors.link,
+        link_visited = theme.colors.link_visited,
+        accent = theme.colors.accent,
+    );
+
+    let lines = snippet.lines.iter().enumerate().map(|(i, tokens)| {
+        html! {
+            <>
+                <span class="line-number" aria-hidden="true">{ snippet.first_line + i }</span>
+                { for tokens.iter().map(|(highlight, text)| match highlight.class() {
+                    Some(class) => html! { <span {class}>{ *text }</span> },
+                    None => html! { *text },
+                }) }
+                { "\n" }
+            </>
+        }
+    });
+
+    html! {
+        <div class={style}>
+            <div class="snippet-title">{ snippet.title() }</div>
+            <pre><code>{ for lines }</code></pre>
+        </div>
+    }
+}
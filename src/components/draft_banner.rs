@@ -0,0 +1,53 @@
+use stylist::css;
+use yew::prelude::*;
+
+use crate::hooks::use_theme;
+use crate::posts::Post;
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub post: Post,
+}
+
+/// Marks an unpublished post being previewed, so a draft is never mistaken for the real thing.
+#[function_component(DraftBanner)]
+pub fn component(props: &Props) -> Html {
+    let theme = use_theme().theme;
+    let style = css!(
+        r#"
+            padding: ${space_sm} ${space_md};
+            background-color: ${accent};
+            color: ${background};
+            font-family: ${font};
+            text-align: center;
+
+            strong {
+                margin-right: ${space_sm};
+                letter-spacing: 0.1em;
+            }
+        "#,
+        space_sm = theme.spacing.sm,
+        space_md = theme.spacing.md,
+        accent = theme.colors.accent,
+        background = theme.colors.background,
+        font = theme.fonts.body,
+    );
+
+    let status = if props.post.draft {
+        "This post is a draft and is hidden from listings and the feed in published builds."
+            .to_string()
+    } else {
+        format!(
+            "This post is scheduled for {} and is hidden from listings and the feed until then in \
+             published builds.",
+            props.post.date
+        )
+    };
+
+    html! {
+        <div class={style} role="status">
+            <strong>{ "DRAFT" }</strong>
+            { status }
+        </div>
+    }
+}
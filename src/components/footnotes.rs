@@ -0,0 +1,279 @@
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::rc::Rc;
+
+use stylist::css;
+use web_sys::Node;
+use yew::prelude::*;
+
+use crate::hooks::use_theme;
+use crate::theme::Breakpoints;
+
+/// A footnote's text and the reference to it in the post.
+#[derive(Clone, PartialEq)]
+struct Note {
+    content: Html,
+    reference: NodeRef,
+}
+
+/// Lets footnotes inside a [`FootnotesProvider`] register themselves and learn their number.
+#[derive(Clone)]
+pub struct FootnotesContext {
+    notes: Rc<RefCell<Vec<Note>>>,
+    changed: Callback<()>,
+    // Bumped whenever the notes change, so footnotes re-render with their new numbers.
+    version: usize,
+}
+
+impl PartialEq for FootnotesContext {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.notes, &other.notes) && self.version == other.version
+    }
+}
+
+impl FootnotesContext {
+    fn register(&self, note: Note) {
+        self.notes.borrow_mut().push(note);
+        self.changed.emit(());
+    }
+
+    fn unregister(&self, reference: &NodeRef) {
+        self.notes
+            .borrow_mut()
+            .retain(|note| &note.reference != reference);
+        self.changed.emit(());
+    }
+
+    /// The 1-based number of the footnote referenced at `reference`, once it's registered.
+    fn number(&self, reference: &NodeRef) -> Option<usize> {
+        self.notes
+            .borrow()
+            .iter()
+            .position(|note| &note.reference == reference)
+            .map(|index| index + 1)
+    }
+}
+
+/// Orders notes as their references appear in the document.
+fn document_order(a: &Note, b: &Note) -> Ordering {
+    match (a.reference.get(), b.reference.get()) {
+        (Some(a), Some(b))
+            if a.compare_document_position(&b) & Node::DOCUMENT_POSITION_FOLLOWING != 0 =>
+        {
+            Ordering::Less
+        }
+        (Some(a), Some(b)) if !a.is_same_node(Some(&b)) => Ordering::Greater,
+        _ => Ordering::Equal,
+    }
+}
+
+/// Counts changes to the notes. A reducer rather than a state so the provider's `changed` callback,
+/// created once, always bumps the latest count instead of the one it first saw.
+#[derive(Default)]
+struct Version(usize);
+
+impl Reducible for Version {
+    type Action = ();
+
+    fn reduce(self: Rc<Self>, _: ()) -> Rc<Self> {
+        Rc::new(Version(self.0 + 1))
+    }
+}
+
+/// Scrolls to the element with `id` without touching history, which would otherwise count as a
+/// navigation and reset the scroll position.
+fn jump(id: String) -> Callback<MouseEvent> {
+    Callback::from(move |e: MouseEvent| {
+        e.prevent_default();
+        if let Some(element) = gloo_utils::document().get_element_by_id(&id) {
+            element.scroll_into_view();
+        }
+    })
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ProviderProps {
+    pub children: Children,
+}
+
+/// Numbers the footnotes rendered inside it in the order they appear, and lists them after its
+/// children with links back to where each was referenced.
+#[function_component(FootnotesProvider)]
+pub fn provider(props: &ProviderProps) -> Html {
+    let theme = use_theme().theme;
+    let notes = use_mut_ref(Vec::<Note>::new);
+    let version = use_reducer(Version::default);
+
+    let changed = {
+        let notes = notes.clone();
+        let bump = version.dispatcher();
+        use_callback(
+            move |_: (), _| {
+                // Footnotes register as they mount, which isn't necessarily the order they
+                // appear in.
+                notes.borrow_mut().sort_by(document_order);
+                bump.dispatch(());
+            },
+            (),
+        )
+    };
+    let context = FootnotesContext {
+        notes: notes.clone(),
+        changed,
+        version: version.0,
+    };
+
+    let style = css!(
+        r#"
+            display: contents;
+
+            .footnote-ref {
+                font-size: 0.7em;
+                line-height: 0;
+            }
+
+            .footnote-ref a {
+                padding: 0 ${space_xs};
+                text-decoration: none;
+            }
+
+            .sidenote {
+                display: none;
+            }
+
+            @media ${wide} {
+                .sidenote {
+                    display: block;
+                    float: right;
+                    clear: right;
+                    box-sizing: border-box;
+                    width: 160px;
+                    margin-right: calc(-160px - 2 * ${space_md});
+                    color: ${muted};
+                    font-size: 0.7em;
+                    line-height: 1.4;
+                    text-align: left;
+                }
+            }
+
+            .sidenote-number {
+                margin-right: ${space_xs};
+                font-weight: bold;
+            }
+
+            .footnotes {
+                box-sizing: border-box;
+                width: 100%;
+                max-width: 800px;
+                margin-top: ${space_lg};
+                padding: 0 ${space_md};
+                border-top: 1px solid ${border};
+                font-size: 0.8em;
+                text-align: left;
+            }
+
+            .footnotes li {
+                margin: ${space_sm} 0;
+                line-height: 1.5;
+            }
+        "#,
+        wide = Breakpoints::above(theme.breakpoints.wide),
+        space_xs = theme.spacing.xs,
+        space_sm = theme.spacing.sm,
+        space_md = theme.spacing.md,
+        space_lg = theme.spacing.lg,
+        muted = theme.colors.muted,
+        border = theme.colors.border,
+    );
+
+    let notes = notes.borrow().clone();
+    html! {
+        <ContextProvider<FootnotesContext> {context}>
+            <div class={style}>
+                { for props.children.iter() }
+                if !notes.is_empty() {
+                    <section class="footnotes" aria-labelledby="footnotes-label">
+                        <h2 id="footnotes-label">{ "Notes" }</h2>
+                        <ol>
+                            { for notes.into_iter().enumerate().map(|(index, note)| {
+                                let number = index + 1;
+                                html! {
+                                    <li id={format!("fn-{number}")}>
+                                        { note.content }
+                                        { " " }
+                                        <a
+                                            href={format!("#fnref-{number}")}
+                                            onclick={jump(format!("fnref-{number}"))}
+                                            aria-label={format!("Back to reference {number}")}
+                                        >
+                                            { "↩" }
+                                        </a>
+                                    </li>
+                                }
+                            }) }
+                        </ol>
+                    </section>
+                }
+            </div>
+        </ContextProvider<FootnotesContext>>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub children: Children,
+}
+
+/// A numbered reference to a note, whose text is listed at the end of the post and, on wide
+/// screens, shown in the margin beside the paragraph. In `markdown!`, written as `[^label]` with
+/// the note defined as `[^label]: ...`.
+#[function_component(Footnote)]
+pub fn component(props: &Props) -> Html {
+    let footnotes = use_context::<FootnotesContext>();
+    let reference = use_node_ref();
+    {
+        let footnotes = footnotes.clone();
+        let note = Note {
+            content: html! { for props.children.iter() },
+            reference: reference.clone(),
+        };
+        use_effect_with_deps(
+            move |_| {
+                if let Some(footnotes) = &footnotes {
+                    footnotes.register(note.clone());
+                }
+                move || {
+                    if let Some(footnotes) = footnotes {
+                        footnotes.unregister(&note.reference);
+                    }
+                }
+            },
+            props.children.clone(),
+        );
+    }
+
+    let number = footnotes
+        .and_then(|footnotes| footnotes.number(&reference))
+        .map(|number| number.to_string())
+        .unwrap_or_default();
+
+    html! {
+        <>
+            <sup class="footnote-ref" ref={reference}>
+                <a
+                    id={format!("fnref-{number}")}
+                    href={format!("#fn-{number}")}
+                    onclick={jump(format!("fn-{number}"))}
+                    aria-label={format!("Note {number}")}
+                >
+                    { &number }
+                </a>
+            </sup>
+            // The notes list already reads the note out, so screen readers skip this copy.
+            <span class="sidenote" aria-hidden="true">
+                <span class="sidenote-number">{ &number }</span>
+                { for props.children.iter() }
+            </span>
+        </>
+    }
+}
@@ -0,0 +1,113 @@
+use yew::prelude::*;
+
+use crate::assets::ImageAsset;
+use crate::components::lightbox::{Entry, LightboxContext};
+
+/// How wide images are laid out, matching the `max-width`s in `blog.css`, so the browser can
+/// pick a variant from the `srcset` before the stylesheet applies.
+const SIZES: &str = "(max-width: 800px) 100vw, 800px";
+const NARROW_SIZES: &str = "(max-width: 500px) 100vw, 500px";
+
+// Generated by the build script: a compile error for each image in a post whose alt text is
+// empty or a placeholder like "image".
+include!(concat!(env!("OUT_DIR"), "/alts.rs"));
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub src: ImageAsset,
+    /// What the image shows, for readers who can't see it.
+    pub alt: AttrValue,
+    /// Cap the width for small screenshots that look blurry at full width.
+    #[prop_or_default]
+    pub narrow: bool,
+    /// Optional caption, rendered below the image.
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// A lazily-loaded post image with its intrinsic size set, so the page doesn't jump as it loads,
+/// offered in every size and format the build encoded it in. Inside a
+/// [`LightboxProvider`](crate::components::lightbox::LightboxProvider), clicking it shows it full
+/// size.
+#[function_component(Image)]
+pub fn component(props: &Props) -> Html {
+    let lightbox = use_context::<LightboxContext>();
+    let trigger = use_node_ref();
+    {
+        let lightbox = lightbox.clone();
+        let entry = Entry {
+            src: props.src,
+            alt: props.alt.clone(),
+            trigger: trigger.clone(),
+        };
+        use_effect_with_deps(
+            move |_| {
+                if let Some(lightbox) = &lightbox {
+                    lightbox.register(entry.clone());
+                }
+                move || {
+                    if let Some(lightbox) = lightbox {
+                        lightbox.unregister(&entry.trigger);
+                    }
+                }
+            },
+            (props.src, props.alt.clone()),
+        );
+    }
+
+    let img = html! {
+        <img
+            src={props.src.url}
+            alt={props.alt.clone()}
+            width={props.src.width.to_string()}
+            height={props.src.height.to_string()}
+            loading="lazy"
+            decoding="async"
+            class={classes!(props.narrow.then_some("narrow"))}
+        />
+    };
+    let img = if props.src.sources.is_empty() {
+        img
+    } else {
+        let sizes = if props.narrow { NARROW_SIZES } else { SIZES };
+        html! {
+            <picture>
+                { for props.src.sources.iter().map(|source| html! {
+                    <source type={source.mime} srcset={source.srcset} {sizes} />
+                }) }
+                { img }
+            </picture>
+        }
+    };
+    let img = match lightbox {
+        Some(lightbox) => {
+            let onclick = {
+                let trigger = trigger.clone();
+                Callback::from(move |_| lightbox.open(trigger.clone()))
+            };
+            html! {
+                <button
+                    ref={trigger}
+                    type="button"
+                    class="lightbox-trigger"
+                    aria-label={format!("View full size: {}", props.alt)}
+                    {onclick}
+                >
+                    { img }
+                </button>
+            }
+        }
+        None => img,
+    };
+
+    if props.children.is_empty() {
+        img
+    } else {
+        html! {
+            <figure>
+                { img }
+                <figcaption>{ for props.children.iter() }</figcaption>
+            </figure>
+        }
+    }
+}
@@ -0,0 +1,328 @@
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::rc::Rc;
+
+use stylist::css;
+use web_sys::{HtmlElement, Node};
+use yew::prelude::*;
+
+use crate::assets::ImageAsset;
+use crate::hooks::use_theme;
+
+/// An image that can be opened in the lightbox.
+#[derive(Clone, PartialEq)]
+pub struct Entry {
+    pub src: ImageAsset,
+    pub alt: AttrValue,
+    /// The button that opens the image, which gets focus back when the lightbox closes.
+    pub trigger: NodeRef,
+}
+
+/// Lets images inside a [`LightboxProvider`] register themselves and open the lightbox.
+#[derive(Clone)]
+pub struct LightboxContext {
+    entries: Rc<RefCell<Vec<Entry>>>,
+    open: Callback<NodeRef>,
+}
+
+impl PartialEq for LightboxContext {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.entries, &other.entries) && self.open == other.open
+    }
+}
+
+impl LightboxContext {
+    pub fn register(&self, entry: Entry) {
+        self.entries.borrow_mut().push(entry);
+    }
+
+    pub fn unregister(&self, trigger: &NodeRef) {
+        self.entries
+            .borrow_mut()
+            .retain(|entry| &entry.trigger != trigger);
+    }
+
+    /// Shows the image opened by `trigger`.
+    pub fn open(&self, trigger: NodeRef) {
+        self.open.emit(trigger);
+    }
+}
+
+/// Orders entries as their triggers appear in the document.
+fn document_order(a: &Entry, b: &Entry) -> Ordering {
+    match (a.trigger.get(), b.trigger.get()) {
+        (Some(a), Some(b))
+            if a.compare_document_position(&b) & Node::DOCUMENT_POSITION_FOLLOWING != 0 =>
+        {
+            Ordering::Less
+        }
+        (Some(a), Some(b)) if !a.is_same_node(Some(&b)) => Ordering::Greater,
+        _ => Ordering::Equal,
+    }
+}
+
+fn focus(node: &NodeRef) {
+    if let Some(element) = node.cast::<HtmlElement>() {
+        let _ = element.focus();
+    }
+}
+
+fn has_focus(node: &NodeRef) -> bool {
+    let active = gloo_utils::document().active_element();
+    node.get()
+        .is_some_and(|node| active.is_some_and(|active| node.is_same_node(Some(&active))))
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub children: Children,
+}
+
+/// Shows the post images rendered inside it full size in a modal overlay, with zoom, previous
+/// and next buttons, and the arrow keys and Esc wired up.
+#[function_component(LightboxProvider)]
+pub fn component(props: &Props) -> Html {
+    let theme = use_theme().theme;
+    let entries = use_mut_ref(Vec::<Entry>::new);
+    let current = use_state_eq(|| None::<usize>);
+    let zoomed = use_state_eq(|| false);
+
+    let zoom_button = use_node_ref();
+    let close_button = use_node_ref();
+    let next_button = use_node_ref();
+
+    let open = {
+        let entries = entries.clone();
+        let current = current.clone();
+        let zoomed = zoomed.clone();
+        use_callback(
+            move |trigger: NodeRef, _| {
+                // Images register as they mount, which isn't necessarily the order they appear
+                // on the page.
+                entries.borrow_mut().sort_by(document_order);
+                let index = entries
+                    .borrow()
+                    .iter()
+                    .position(|entry| entry.trigger == trigger);
+                zoomed.set(false);
+                current.set(index);
+            },
+            (),
+        )
+    };
+    let context = LightboxContext {
+        entries: entries.clone(),
+        open,
+    };
+
+    // Move focus into the dialog as it opens.
+    {
+        let close_button = close_button.clone();
+        use_effect_with_deps(
+            move |is_open| {
+                if *is_open {
+                    focus(&close_button);
+                }
+            },
+            current.is_some(),
+        );
+    }
+
+    let style = css!(
+        r#"
+            position: fixed;
+            inset: 0;
+            z-index: 1000;
+            display: grid;
+            grid-template-columns: auto 1fr auto;
+            grid-template-rows: auto 1fr auto;
+            gap: ${space_sm};
+            padding: ${space_sm};
+            background-color: rgba(0, 0, 0, 0.9);
+            color: #fff;
+            font-family: ${font};
+
+            button {
+                min-width: 44px;
+                min-height: 44px;
+                padding: 0 ${space_sm};
+                border: 2px solid ${border};
+                border-radius: ${radius};
+                background-color: ${background};
+                color: ${text};
+                font-size: 1.25em;
+                cursor: pointer;
+            }
+
+            button:focus-visible {
+                outline: 3px solid ${accent};
+                outline-offset: 2px;
+            }
+
+            .lightbox-toolbar {
+                grid-row: 1;
+                grid-column: 1 / -1;
+                display: flex;
+                align-items: center;
+                justify-content: flex-end;
+                gap: ${space_sm};
+            }
+
+            .lightbox-counter {
+                margin-right: auto;
+            }
+
+            .lightbox-prev,
+            .lightbox-next {
+                grid-row: 2;
+                align-self: center;
+            }
+
+            .lightbox-prev {
+                grid-column: 1;
+            }
+
+            .lightbox-next {
+                grid-column: 3;
+            }
+
+            .lightbox-stage {
+                grid-row: 2;
+                grid-column: 2;
+                display: flex;
+                min-width: 0;
+                min-height: 0;
+                overflow: auto;
+            }
+
+            .lightbox-stage img {
+                margin: auto;
+                max-width: 100%;
+                max-height: 100%;
+                width: auto;
+                height: auto;
+                object-fit: contain;
+                cursor: zoom-in;
+            }
+
+            .lightbox-stage.zoomed img {
+                max-width: none;
+                max-height: none;
+                cursor: zoom-out;
+            }
+
+            .lightbox-caption {
+                grid-row: 3;
+                grid-column: 1 / -1;
+                margin: 0 auto;
+                max-width: 800px;
+                text-align: center;
+            }
+        "#,
+        space_sm = theme.spacing.sm,
+        font = theme.fonts.body,
+        border = theme.colors.border,
+        radius = theme.radii.sm,
+        background = theme.colors.background,
+        text = theme.colors.text,
+        accent = theme.colors.accent,
+    );
+
+    let count = entries.borrow().len();
+    let dialog = current
+        .and_then(|index| Some((index, entries.borrow().get(index)?.clone())))
+        .map(|(index, entry)| {
+            let close = {
+                let current = current.clone();
+                let trigger = entry.trigger.clone();
+                Callback::from(move |_: ()| {
+                    current.set(None);
+                    focus(&trigger);
+                })
+            };
+            let step = {
+                let current = current.clone();
+                let zoomed = zoomed.clone();
+                Callback::from(move |delta: isize| {
+                    zoomed.set(false);
+                    current.set(Some((index as isize + delta).rem_euclid(count as isize) as usize));
+                })
+            };
+            let toggle_zoom = {
+                let zoomed = zoomed.clone();
+                Callback::from(move |e: MouseEvent| {
+                    e.stop_propagation();
+                    zoomed.set(!*zoomed);
+                })
+            };
+
+            let onkeydown = {
+                let close = close.clone();
+                let step = step.clone();
+                let zoom_button = zoom_button.clone();
+                let last = if count > 1 { next_button.clone() } else { close_button.clone() };
+                Callback::from(move |e: KeyboardEvent| match e.key().as_str() {
+                    "Escape" => close.emit(()),
+                    "ArrowLeft" if count > 1 => step.emit(-1),
+                    "ArrowRight" if count > 1 => step.emit(1),
+                    // Keep focus inside the dialog while it's open.
+                    "Tab" if e.shift_key() && has_focus(&zoom_button) => {
+                        e.prevent_default();
+                        focus(&last);
+                    }
+                    "Tab" if !e.shift_key() && has_focus(&last) => {
+                        e.prevent_default();
+                        focus(&zoom_button);
+                    }
+                    _ => {}
+                })
+            };
+
+            let zoom_label = if *zoomed { "Zoom out" } else { "Zoom in" };
+            html! {
+                <div class={style} role="dialog" aria-modal="true" aria-label="Image viewer" {onkeydown}>
+                    <div class="lightbox-toolbar">
+                        <span class="lightbox-counter" aria-live="polite">
+                            { format!("{} / {count}", index + 1) }
+                        </span>
+                        <button ref={zoom_button.clone()} onclick={toggle_zoom.clone()} aria-pressed={zoomed.to_string()}>
+                            { zoom_label }
+                        </button>
+                        <button ref={close_button.clone()} onclick={close.reform(|_| ())} aria-label="Close" title="Close">
+                            { "✕" }
+                        </button>
+                    </div>
+                    if count > 1 {
+                        <button class="lightbox-prev" onclick={step.reform(|_| -1)} aria-label="Previous image" title="Previous image">
+                            { "‹" }
+                        </button>
+                    }
+                    <div
+                        class={classes!("lightbox-stage", zoomed.then_some("zoomed"))}
+                        onclick={close.reform(|_| ())}
+                    >
+                        <img
+                            src={entry.src.url}
+                            alt={entry.alt.clone()}
+                            width={entry.src.width.to_string()}
+                            height={entry.src.height.to_string()}
+                            onclick={toggle_zoom}
+                        />
+                    </div>
+                    if count > 1 {
+                        <button ref={next_button.clone()} class="lightbox-next" onclick={step.reform(|_| 1)} aria-label="Next image" title="Next image">
+                            { "›" }
+                        </button>
+                    }
+                    <div class="lightbox-caption">{ entry.alt.clone() }</div>
+                </div>
+            }
+        });
+
+    html! {
+        <ContextProvider<LightboxContext> {context}>
+            { for props.children.iter() }
+            { dialog.unwrap_or_default() }
+        </ContextProvider<LightboxContext>>
+    }
+}
@@ -0,0 +1,20 @@
+pub mod animated_image;
+pub mod callout;
+pub mod code_snippet;
+pub mod draft_banner;
+pub mod footnotes;
+pub mod image;
+pub mod lightbox;
+pub mod outdated_notice;
+pub mod page;
+pub mod post_list;
+pub mod quote;
+pub mod reading_progress;
+pub mod revision_history;
+pub mod runnable_code;
+pub mod scroll_manager;
+pub mod site_footer;
+pub mod site_header;
+pub mod tabs;
+pub mod theme_toggle;
+pub mod video_embed;
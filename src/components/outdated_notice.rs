@@ -0,0 +1,38 @@
+use yew::prelude::*;
+
+use crate::components::callout::{Callout, CalloutKind};
+use crate::posts::{OutdatedCrate, Post};
+
+fn describe(krate: &OutdatedCrate) -> String {
+    match krate.current {
+        Some(current) => format!(
+            "This post was written for {} {}; this site now runs {current}.",
+            krate.name, krate.written_for
+        ),
+        None => format!(
+            "This post was written for {} {}, which this site no longer uses.",
+            krate.name, krate.written_for
+        ),
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub post: Post,
+}
+
+/// Warns that the crates a post was written against have moved on, so its code may need
+/// changes. Renders nothing while they're still current.
+#[function_component(OutdatedNotice)]
+pub fn component(props: &Props) -> Html {
+    if props.post.outdated.is_empty() {
+        return Html::default();
+    }
+
+    html! {
+        <Callout kind={CalloutKind::Warning} title="Outdated versions">
+            { for props.post.outdated.iter().map(|krate| html! { <p>{ describe(krate) }</p> }) }
+            <p>{ "Some of the code may need changes to work with the newer versions." }</p>
+        </Callout>
+    }
+}
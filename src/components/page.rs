@@ -0,0 +1,73 @@
+use stylist::css;
+use stylist::yew::use_style;
+use yew::prelude::*;
+
+use crate::components::footnotes::FootnotesProvider;
+use crate::components::lightbox::LightboxProvider;
+use crate::components::tabs::TabsProvider;
+use crate::hooks::use_theme;
+use crate::theme::Breakpoints;
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub children: Children,
+}
+
+/// Frame shared by every page: the prose stylesheet, the content card with its footnotes, and the
+/// overrides that let both collapse gracefully on narrow screens.
+#[function_component(Page)]
+pub fn component(props: &Props) -> Html {
+    let theme = use_theme().theme;
+    let prose = use_style(crate::styles::blog());
+    let responsive = css!(
+        r#"
+            @media ${tablet} {
+                .blog-body {
+                    padding-bottom: ${space_lg};
+                }
+
+                .blog-content-container {
+                    width: calc(100% - 2 * ${space_md});
+                }
+            }
+
+            @media ${mobile} {
+                .blog-content-container {
+                    width: 100%;
+                    border-radius: 0;
+                    border-left: none;
+                    border-right: none;
+                }
+
+                .blog-content-container p,
+                .blog-content-container h1,
+                .blog-content-container h2,
+                .blog-content-container h3 {
+                    padding-left: ${space_sm};
+                    padding-right: ${space_sm};
+                }
+            }
+        "#,
+        tablet = Breakpoints::below(theme.breakpoints.tablet),
+        mobile = Breakpoints::below(theme.breakpoints.mobile),
+        space_sm = theme.spacing.sm,
+        space_md = theme.spacing.md,
+        space_lg = theme.spacing.lg,
+    );
+
+    html! {
+        <div class={classes!(prose, responsive)}>
+            <div class="blog-body">
+                <div class="blog-content-container">
+                    <LightboxProvider>
+                        <TabsProvider>
+                            <FootnotesProvider>
+                                { for props.children.iter() }
+                            </FootnotesProvider>
+                        </TabsProvider>
+                    </LightboxProvider>
+                </div>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,30 @@
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use crate::posts::Post;
+use crate::router::Route;
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub posts: Vec<&'static Post>,
+}
+
+/// Titles of `posts` linking to each post, with its date, reading time and tags.
+#[function_component(PostList)]
+pub fn component(props: &Props) -> Html {
+    html! {
+        <ul class="post-list">
+            { for props.posts.iter().map(|post| html! {
+                <li>
+                    <h2><Link<Route> to={post.route.clone()}>{ post.title }</Link<Route>></h2>
+                    <h3 class="reading-time">{ format!("{} · {}", post.date, post.reading_time()) }</h3>
+                    <div class="post-tags">
+                        { for post.tags.iter().map(|tag| html! {
+                            <Link<Route> to={Route::Tag { tag: tag.to_string() }}>{ format!("#{tag}") }</Link<Route>>
+                        }) }
+                    </div>
+                </li>
+            }) }
+        </ul>
+    }
+}
@@ -0,0 +1,81 @@
+use stylist::css;
+use yew::prelude::*;
+
+use crate::hooks::use_theme;
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    /// Who or what is being quoted, eg. "Taxus baccata, Wikipedia".
+    #[prop_or_default]
+    pub attribution: Option<AttrValue>,
+    /// URL of the page the quote is from, which the attribution links to.
+    #[prop_or_default]
+    pub source: Option<AttrValue>,
+    pub children: Children,
+}
+
+/// A quotation set apart from the prose, with its attribution underneath. In `markdown!`, written
+/// as a blockquote whose last paragraph starts with an em dash: `> — [Wikipedia](https://...)`.
+#[function_component(Quote)]
+pub fn component(props: &Props) -> Html {
+    let theme = use_theme().theme;
+    let style = css!(
+        r#"
+            box-sizing: border-box;
+            width: min(100% - 2 * ${space_md}, 800px);
+
+            blockquote {
+                margin: 0;
+                padding: ${space_sm} ${space_md};
+                border-left: 6px solid ${accent};
+                border-radius: ${radius};
+                background-color: ${code_background};
+                font-style: italic;
+            }
+
+            blockquote p {
+                margin: ${space_sm} 0;
+                padding: 0;
+            }
+
+            .quote-attribution {
+                margin-top: ${space_sm};
+                color: ${muted};
+                font-size: 0.8em;
+                font-weight: normal;
+                text-align: right;
+            }
+
+            .quote-attribution cite {
+                font-style: normal;
+            }
+        "#,
+        space_sm = theme.spacing.sm,
+        space_md = theme.spacing.md,
+        accent = theme.colors.accent,
+        radius = theme.radii.sm,
+        code_background = theme.colors.code_background,
+        muted = theme.colors.muted,
+    );
+
+    let attribution = props
+        .attribution
+        .clone()
+        .map(|attribution| match &props.source {
+            Some(source) => html! { <a href={source.clone()}>{ attribution }</a> },
+            None => html! { attribution },
+        });
+
+    html! {
+        <figure class={style}>
+            <blockquote cite={props.source.clone()}>
+                { for props.children.iter() }
+            </blockquote>
+            if let Some(attribution) = attribution {
+                <figcaption class="quote-attribution">
+                    { "— " }<cite>{ attribution }</cite>
+                </figcaption>
+            }
+        </figure>
+    }
+}
@@ -0,0 +1,44 @@
+use stylist::css;
+use yew::prelude::*;
+
+use crate::hooks::{use_scroll_progress, use_theme};
+
+/// Thin bar pinned to the top of a post that fills as the reader scrolls.
+#[function_component(ReadingProgress)]
+pub fn component() -> Html {
+    let theme = use_theme().theme;
+    let percent = use_scroll_progress() * 100.0;
+
+    let track = css!(
+        r#"
+            position: sticky;
+            top: 0;
+            align-self: stretch;
+            height: 4px;
+            border-radius: ${radius} ${radius} 0 0;
+            overflow: hidden;
+            z-index: 1;
+        "#,
+        radius = theme.radii.lg,
+    );
+    let bar = css!(
+        r#"
+            height: 100%;
+            background-color: ${accent};
+        "#,
+        accent = theme.colors.accent,
+    );
+
+    html! {
+        <div
+            class={track}
+            role="progressbar"
+            aria-label="Reading progress"
+            aria-valuemin="0"
+            aria-valuemax="100"
+            aria-valuenow={format!("{percent:.0}")}
+        >
+            <div class={bar} style={format!("width: {percent:.2}%;")} />
+        </div>
+    }
+}
@@ -0,0 +1,78 @@
+use stylist::css;
+use yew::prelude::*;
+
+use crate::hooks::use_theme;
+use crate::posts::Post;
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub post: Post,
+}
+
+/// When the post was last updated and, in an expandable list, what changed in each revision.
+/// Renders nothing for a post that hasn't changed since it was published.
+#[function_component(RevisionHistory)]
+pub fn component(props: &Props) -> Html {
+    let theme = use_theme().theme;
+    let post = &props.post;
+
+    let style = css!(
+        r#"
+            margin: -10px auto ${space_md};
+            max-width: 800px;
+            color: ${muted};
+            font-size: 0.8em;
+
+            summary {
+                cursor: pointer;
+            }
+
+            ul {
+                margin: ${space_sm} 0;
+                padding-left: ${space_lg};
+                text-align: left;
+            }
+
+            li {
+                margin-bottom: ${space_xs};
+            }
+
+            time {
+                font-weight: bold;
+            }
+        "#,
+        space_xs = theme.spacing.xs,
+        space_sm = theme.spacing.sm,
+        space_md = theme.spacing.md,
+        space_lg = theme.spacing.lg,
+        muted = theme.colors.muted,
+    );
+
+    if post.updated.is_none() && post.revisions.is_empty() {
+        return Html::default();
+    }
+
+    html! {
+        <div class={style}>
+            if let Some(updated) = post.updated {
+                <div>
+                    { "Last updated " }
+                    <time datetime={updated.iso()}>{ updated.to_string() }</time>
+                </div>
+            }
+            if !post.revisions.is_empty() {
+                <details>
+                    <summary>{ format!("Revision history ({})", post.revisions.len()) }</summary>
+                    <ul>
+                        { for post.revisions.iter().map(|revision| html! {
+                            <li>
+                                <time datetime={revision.date.iso()}>{ revision.date.to_string() }</time>
+                                { format!(": {}", revision.note) }
+                            </li>
+                        }) }
+                    </ul>
+                </details>
+            }
+        </div>
+    }
+}
@@ -0,0 +1,194 @@
+use gloo_net::http::Request;
+use serde::{Deserialize, Serialize};
+use stylist::css;
+use yew::platform::spawn_local;
+use yew::prelude::*;
+
+use crate::hooks::use_theme;
+
+/// Where the Run button posts examples: anything that speaks the Rust Playground's `/execute`,
+/// like `https://play.rust-lang.org/execute`. Set `PLAYGROUND_URL` when building to change it;
+/// by default it's the stand-in from `cargo run -p site-tools --bin playground`.
+const PLAYGROUND_URL: &str = match option_env!("PLAYGROUND_URL") {
+    Some(url) => url,
+    None => "http://127.0.0.1:3001/execute",
+};
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Execute<'a> {
+    channel: &'a str,
+    mode: &'a str,
+    edition: &'a str,
+    crate_type: &'a str,
+    tests: bool,
+    backtrace: bool,
+    code: &'a str,
+}
+
+#[derive(Clone, PartialEq, Deserialize)]
+struct Executed {
+    success: bool,
+    stdout: String,
+    stderr: String,
+}
+
+#[derive(Clone, PartialEq)]
+enum Run {
+    Idle,
+    Running,
+    Finished(Executed),
+    /// The playground couldn't be reached or didn't answer sensibly.
+    Failed(String),
+}
+
+async fn execute(code: &str) -> Result<Executed, String> {
+    let request = Request::post(PLAYGROUND_URL)
+        .json(&Execute {
+            channel: "stable",
+            mode: "debug",
+            edition: "2021",
+            crate_type: "bin",
+            tests: false,
+            backtrace: false,
+            code,
+        })
+        .map_err(|e| e.to_string())?;
+    let response = request.send().await.map_err(|e| e.to_string())?;
+    if !response.ok() {
+        return Err(format!(
+            "the playground responded with {}",
+            response.status()
+        ));
+    }
+    response.json().await.map_err(|e| e.to_string())
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub code: AttrValue,
+    /// The code as shown, when it leaves out setup lines `code` needs to compile.
+    #[prop_or_default]
+    pub shown: Option<AttrValue>,
+}
+
+/// A Rust example with a Run button that compiles and runs it on the playground, showing what
+/// it printed underneath. In `markdown!`, written as a code block tagged ```` ```rust,runnable ````.
+#[function_component(RunnableCode)]
+pub fn component(props: &Props) -> Html {
+    let theme = use_theme().theme;
+    let run = use_state_eq(|| Run::Idle);
+
+    let onclick = {
+        let run = run.clone();
+        let code = props.code.clone();
+        Callback::from(move |_| {
+            let run = run.clone();
+            let code = code.clone();
+            run.set(Run::Running);
+            spawn_local(async move {
+                run.set(match execute(&code).await {
+                    Ok(executed) => Run::Finished(executed),
+                    Err(e) => Run::Failed(format!("Couldn't reach {PLAYGROUND_URL}: {e}")),
+                });
+            });
+        })
+    };
+
+    let style = css!(
+        r#"
+            box-sizing: border-box;
+            width: min(100% - 2 * ${space_md}, 800px);
+            margin: ${space_sm} 0;
+            border: 2px solid ${border};
+            border-radius: ${radius};
+            background-color: ${code_background};
+            font-family: ${mono};
+            text-align: left;
+
+            pre {
+                margin: 0;
+                padding: ${space_sm};
+                overflow-x: auto;
+                font-family: inherit;
+            }
+
+            .run-toolbar {
+                display: flex;
+                justify-content: flex-end;
+                padding: ${space_xs} ${space_sm};
+                border-top: 2px solid ${border};
+            }
+
+            .run-toolbar button {
+                padding: ${space_xs} ${space_sm};
+                border: 2px solid ${accent};
+                border-radius: ${radius};
+                background-color: ${accent};
+                color: ${background};
+                font: inherit;
+                cursor: pointer;
+            }
+
+            .run-toolbar button:disabled {
+                opacity: 0.6;
+                cursor: progress;
+            }
+
+            .run-toolbar button:focus-visible {
+                outline: 3px solid ${text};
+                outline-offset: 2px;
+            }
+
+            .run-output {
+                border-top: 2px solid ${border};
+                font-size: 0.8em;
+            }
+
+            .run-stderr,
+            .run-failed {
+                color: ${danger};
+            }
+        "#,
+        space_xs = theme.spacing.xs,
+        space_sm = theme.spacing.sm,
+        space_md = theme.spacing.md,
+        border = theme.colors.border,
+        radius = theme.radii.sm,
+        code_background = theme.colors.code_background,
+        mono = theme.fonts.mono,
+        accent = theme.colors.accent,
+        background = theme.colors.background,
+        text = theme.colors.text,
+        danger = theme.colors.danger,
+    );
+
+    let output = match &*run {
+        Run::Idle => Html::default(),
+        Run::Running => html! { <pre>{ "Running…" }</pre> },
+        Run::Finished(executed) => html! {
+            <>
+                if !executed.stdout.is_empty() {
+                    <pre class="run-stdout">{ &executed.stdout }</pre>
+                }
+                if !executed.stderr.is_empty() {
+                    <pre class="run-stderr">{ &executed.stderr }</pre>
+                }
+                if executed.stdout.is_empty() && executed.stderr.is_empty() {
+                    <pre>{ if executed.success { "(no output)" } else { "(failed with no output)" } }</pre>
+                }
+            </>
+        },
+        Run::Failed(message) => html! { <pre class="run-failed">{ message }</pre> },
+    };
+
+    html! {
+        <div class={style}>
+            <pre><code>{ props.shown.clone().unwrap_or_else(|| props.code.clone()) }</code></pre>
+            <div class="run-toolbar">
+                <button {onclick} disabled={*run == Run::Running}>{ "Run ▶" }</button>
+            </div>
+            <div class="run-output" aria-live="polite" hidden={*run == Run::Idle}>{ output }</div>
+        </div>
+    }
+}
@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+
+use gloo_events::EventListener;
+use web_sys::ScrollRestoration;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub children: Children,
+}
+
+/// Key for the current history entry. gloo-history tags every entry it pushes with an id in
+/// `history.state`; entries it didn't create (eg. the initial page load) fall back to the path.
+fn entry_key() -> String {
+    let path = gloo_utils::window()
+        .location()
+        .pathname()
+        .unwrap_or_default();
+    let id = gloo_utils::history()
+        .state()
+        .ok()
+        .and_then(|state| js_sys::Reflect::get(&state, &js_sys::JsString::from("id")).ok())
+        .and_then(|id| id.as_f64());
+
+    match id {
+        Some(id) => format!("{id}:{path}"),
+        None => path,
+    }
+}
+
+/// Remembers the scroll position of every history entry. Back/forward navigation restores the
+/// saved position, anything else starts at the top of the page.
+#[function_component(ScrollManager)]
+pub fn component(props: &Props) -> Html {
+    // Subscribes to route changes so the restore effect below runs on every navigation.
+    let _location = use_location();
+    let key = entry_key();
+
+    let positions = use_mut_ref(HashMap::<String, i32>::new);
+    let popped = use_mut_ref(|| false);
+
+    {
+        let positions = positions.clone();
+        let popped = popped.clone();
+        use_effect_with_deps(
+            move |_| {
+                let _ = gloo_utils::history().set_scroll_restoration(ScrollRestoration::Manual);
+
+                let window = gloo_utils::window();
+                let on_scroll = EventListener::new(&window, "scroll", move |_| {
+                    let top = gloo_utils::document_element().scroll_top();
+                    positions.borrow_mut().insert(entry_key(), top);
+                });
+                // popstate fires before the router re-renders, so the flag is set by the time
+                // the restore effect runs.
+                let on_popstate = EventListener::new(&window, "popstate", move |_| {
+                    *popped.borrow_mut() = true;
+                });
+
+                move || {
+                    drop(on_scroll);
+                    drop(on_popstate);
+                }
+            },
+            (),
+        );
+    }
+
+    use_effect_with_deps(
+        move |key: &String| {
+            let restored = if popped.replace(false) {
+                positions.borrow().get(key).copied()
+            } else {
+                None
+            };
+            gloo_utils::document_element().set_scroll_top(restored.unwrap_or(0));
+        },
+        key,
+    );
+
+    html! { <>{ for props.children.iter() }</> }
+}
@@ -0,0 +1,43 @@
+use stylist::css;
+use yew::prelude::*;
+
+use crate::hooks::use_theme;
+use crate::posts::{FEED_URL, SITE_AUTHOR};
+
+const SOURCE_URL: &str = "https://github.com/M-Kokolich/rust-site";
+
+/// Copyright, feed and source links, shown below every page.
+#[function_component(SiteFooter)]
+pub fn component() -> Html {
+    let theme = use_theme().theme;
+    let year = js_sys::Date::new_0().get_full_year();
+
+    let style = css!(
+        r#"
+            display: flex;
+            flex-wrap: wrap;
+            justify-content: center;
+            gap: ${space_md};
+            padding: ${space_md};
+            font-family: ${font};
+            font-size: 0.9em;
+            color: ${muted};
+
+            a {
+                color: ${link};
+            }
+        "#,
+        space_md = theme.spacing.md,
+        font = theme.fonts.body,
+        muted = theme.colors.muted,
+        link = theme.colors.link,
+    );
+
+    html! {
+        <footer class={style}>
+            <span>{ format!("© {year} {SITE_AUTHOR}") }</span>
+            <a href={FEED_URL} type="application/atom+xml">{ "Atom feed" }</a>
+            <a href={SOURCE_URL}>{ "Source on GitHub" }</a>
+        </footer>
+    }
+}
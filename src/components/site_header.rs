@@ -0,0 +1,105 @@
+use stylist::css;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use crate::components::theme_toggle::ThemeToggle;
+use crate::hooks::use_theme;
+use crate::posts::SITE_TITLE;
+use crate::router::Route;
+
+/// Whether the nav link to `target` should be highlighted while `current` is displayed.
+fn is_active(current: &Route, target: &Route) -> bool {
+    match (current, target) {
+        (Route::Tag { .. }, Route::Tags) => true,
+        _ => current == target,
+    }
+}
+
+/// Logo and site navigation, shown above every page.
+#[function_component(SiteHeader)]
+pub fn component() -> Html {
+    let theme = use_theme().theme;
+    let current = use_route::<Route>();
+
+    let nav_link = |to: Route, label: &'static str| {
+        let active = current
+            .as_ref()
+            .is_some_and(|current| is_active(current, &to));
+        html! {
+            <Link<Route> {to} classes={classes!(active.then_some("active"))}>{ label }</Link<Route>>
+        }
+    };
+
+    let style = css!(
+        r#"
+            display: flex;
+            flex-wrap: wrap;
+            align-items: center;
+            justify-content: space-between;
+            gap: ${space_md};
+            padding: ${space_sm} ${space_md};
+            font-family: ${font};
+            background-color: ${background};
+            border-bottom: 3px solid ${border};
+
+            .brand {
+                display: flex;
+                align-items: center;
+                gap: ${space_sm};
+                color: ${text};
+                font-size: 1.25em;
+                font-weight: bold;
+                text-decoration: none;
+            }
+
+            .brand img {
+                width: 40px;
+                height: 40px;
+            }
+
+            nav {
+                display: flex;
+                flex-wrap: wrap;
+                align-items: center;
+                gap: ${space_md};
+            }
+
+            nav a {
+                color: ${text};
+                text-decoration: none;
+                border-bottom: 2px solid transparent;
+            }
+
+            nav a:hover,
+            nav a.active {
+                color: ${link};
+                border-bottom-color: ${accent};
+            }
+        "#,
+        space_sm = theme.spacing.sm,
+        space_md = theme.spacing.md,
+        font = theme.fonts.body,
+        background = theme.colors.background,
+        border = theme.colors.border,
+        text = theme.colors.text,
+        link = theme.colors.link,
+        accent = theme.colors.accent,
+    );
+
+    html! {
+        <header class={style}>
+            <Link<Route> to={Route::Home} classes="brand">
+                <img src={asset!("img/fish-icon.png").url} alt="" />
+                { SITE_TITLE }
+            </Link<Route>>
+            <nav aria-label="Main">
+                { nav_link(Route::Home, "Home") }
+                { nav_link(Route::Archive, "Archive") }
+                { nav_link(Route::Tags, "Tags") }
+                { nav_link(Route::About, "About") }
+                { nav_link(Route::Search, "Search") }
+                <ThemeToggle />
+            </nav>
+        </header>
+    }
+}
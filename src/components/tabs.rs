@@ -0,0 +1,228 @@
+use std::rc::Rc;
+
+use gloo_storage::{LocalStorage, Storage};
+use stylist::css;
+use web_sys::HtmlElement;
+use yew::prelude::*;
+
+use crate::hooks::use_theme;
+
+/// `localStorage` key holding the tab labels the reader has picked, most recent first.
+const STORAGE_KEY: &str = "tabs";
+
+/// The tabs the reader prefers, which every group of tabs inside a [`TabsProvider`] follows.
+#[derive(Clone, PartialEq)]
+pub struct TabsContext {
+    /// Labels the reader has picked, most recent first.
+    preferred: Vec<String>,
+    choose: Callback<String>,
+}
+
+/// Labels the reader has picked, most recent first. A reducer rather than a state so the
+/// provider's `choose` callback, created once, always builds on the latest choices.
+#[derive(PartialEq)]
+struct Preferred(Vec<String>);
+
+impl Reducible for Preferred {
+    /// A label the reader just picked.
+    type Action = String;
+
+    fn reduce(self: Rc<Self>, label: String) -> Rc<Self> {
+        // Older choices stay, so a group without this label keeps following them.
+        let mut next = self.0.clone();
+        next.retain(|preferred| preferred != &label);
+        next.insert(0, label);
+        Rc::new(Preferred(next))
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ProviderProps {
+    pub children: Children,
+}
+
+/// Remembers which tab the reader picks and switches every group with a tab of the same label to
+/// it, now and on later visits.
+#[function_component(TabsProvider)]
+pub fn provider(props: &ProviderProps) -> Html {
+    let preferred =
+        use_reducer_eq(|| Preferred(LocalStorage::get(STORAGE_KEY).unwrap_or_default()));
+
+    use_effect_with_deps(
+        |preferred| {
+            let _ = LocalStorage::set(STORAGE_KEY, preferred);
+        },
+        preferred.0.clone(),
+    );
+
+    let choose = {
+        let choose = preferred.dispatcher();
+        use_callback(move |label: String, _| choose.dispatch(label), ())
+    };
+    let context = TabsContext {
+        preferred: preferred.0.clone(),
+        choose,
+    };
+
+    html! {
+        <ContextProvider<TabsContext> {context}>
+            { for props.children.iter() }
+        </ContextProvider<TabsContext>>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct TabProps {
+    /// Names the tab, and links it with the tabs of the same name in other groups.
+    pub label: AttrValue,
+    pub children: Children,
+}
+
+/// One variant of the instructions in [`Tabs`], eg. the trunk version of an install step.
+#[function_component(Tab)]
+pub fn tab(props: &TabProps) -> Html {
+    html! { for props.children.iter() }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub children: ChildrenWithProps<Tab>,
+}
+
+/// Code samples for several variants of the same step, like the same command for different
+/// tools, with only the one the reader picked shown.
+#[function_component(Tabs)]
+pub fn component(props: &Props) -> Html {
+    let theme = use_theme().theme;
+    let context = use_context::<TabsContext>();
+    // Without a provider, the choice is only remembered by this group.
+    let local = use_state_eq(|| None::<String>);
+    let buttons: Vec<NodeRef> = (0..props.children.len())
+        .map(|_| NodeRef::default())
+        .collect();
+
+    let labels: Vec<String> = props
+        .children
+        .iter()
+        .map(|tab| tab.props.label.to_string())
+        .collect();
+    let preferred = match &context {
+        Some(context) => context.preferred.clone(),
+        None => local.iter().cloned().collect(),
+    };
+    let selected = preferred
+        .iter()
+        .find_map(|preferred| labels.iter().position(|label| label == preferred))
+        .unwrap_or(0);
+
+    let choose = {
+        let context = context.clone();
+        let local = local.clone();
+        Callback::from(move |label: String| match &context {
+            Some(context) => context.choose.emit(label),
+            None => local.set(Some(label)),
+        })
+    };
+
+    let onkeydown = {
+        let labels = labels.clone();
+        let buttons = buttons.clone();
+        let choose = choose.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            if labels.is_empty() {
+                return;
+            }
+            let next = match e.key().as_str() {
+                "ArrowLeft" => (selected + labels.len() - 1) % labels.len(),
+                "ArrowRight" => (selected + 1) % labels.len(),
+                _ => return,
+            };
+            e.prevent_default();
+            choose.emit(labels[next].clone());
+            if let Some(button) = buttons[next].cast::<HtmlElement>() {
+                let _ = button.focus();
+            }
+        })
+    };
+
+    let style = css!(
+        r#"
+            box-sizing: border-box;
+            width: min(100% - 2 * ${space_md}, 800px);
+            margin: ${space_sm} 0;
+
+            .tab-list {
+                display: flex;
+                flex-wrap: wrap;
+                gap: ${space_xs};
+                border-bottom: 2px solid ${border};
+            }
+
+            .tab-list button {
+                padding: ${space_xs} ${space_sm};
+                border: 2px solid transparent;
+                border-bottom: none;
+                border-radius: ${radius} ${radius} 0 0;
+                background: none;
+                color: ${muted};
+                font: inherit;
+                font-size: 0.8em;
+                cursor: pointer;
+            }
+
+            .tab-list button[aria-selected="true"] {
+                border-color: ${border};
+                background-color: ${code_background};
+                color: ${text};
+                font-weight: bold;
+            }
+
+            .tab-list button:focus-visible {
+                outline: 3px solid ${accent};
+                outline-offset: 2px;
+            }
+
+            .tab-panel p {
+                max-width: none;
+            }
+        "#,
+        space_xs = theme.spacing.xs,
+        space_sm = theme.spacing.sm,
+        space_md = theme.spacing.md,
+        border = theme.colors.border,
+        radius = theme.radii.sm,
+        muted = theme.colors.muted,
+        code_background = theme.colors.code_background,
+        text = theme.colors.text,
+        accent = theme.colors.accent,
+    );
+
+    html! {
+        <div class={style}>
+            <div class="tab-list" role="tablist" {onkeydown}>
+                { for labels.iter().zip(&buttons).enumerate().map(|(index, (label, button))| {
+                    let is_selected = index == selected;
+                    let onclick = {
+                        let choose = choose.clone();
+                        let label = label.clone();
+                        Callback::from(move |_| choose.emit(label.clone()))
+                    };
+                    html! {
+                        <button
+                            ref={button.clone()}
+                            role="tab"
+                            aria-selected={is_selected.to_string()}
+                            tabindex={if is_selected { "0" } else { "-1" }}
+                            {onclick}
+                        >
+                            { label }
+                        </button>
+                    }
+                }) }
+            </div>
+            <div class="tab-panel" role="tabpanel" aria-label={labels.get(selected).cloned()}>
+                { props.children.iter().nth(selected).map(Html::from).unwrap_or_default() }
+            </div>
+        </div>
+    }
+}
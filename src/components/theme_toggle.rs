@@ -0,0 +1,46 @@
+use stylist::css;
+use yew::prelude::*;
+
+use crate::hooks::use_theme;
+use crate::theme::ThemeKind;
+
+/// Button that flips between the light and dark palettes.
+#[function_component(ThemeToggle)]
+pub fn component() -> Html {
+    let theme = use_theme();
+    let tokens = theme.theme;
+
+    let next = theme.kind.toggled();
+    let onclick = {
+        let set_kind = theme.set_kind.clone();
+        Callback::from(move |_| set_kind.emit(next))
+    };
+    let label = match next {
+        ThemeKind::Light => "Switch to light theme",
+        ThemeKind::Dark => "Switch to dark theme",
+    };
+    let icon = match theme.kind {
+        ThemeKind::Light => "🌙",
+        ThemeKind::Dark => "☀️",
+    };
+
+    let style = css!(
+        r#"
+            font-size: 1.25em;
+            line-height: 1;
+            padding: ${padding};
+            border-radius: ${radius};
+            border: 2px solid ${border};
+            background-color: ${background};
+            cursor: pointer;
+        "#,
+        padding = tokens.spacing.sm,
+        radius = tokens.radii.round,
+        border = tokens.colors.border,
+        background = tokens.colors.background,
+    );
+
+    html! {
+        <button class={style} {onclick} aria-label={label} title={label}>{ icon }</button>
+    }
+}
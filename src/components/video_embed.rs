@@ -0,0 +1,161 @@
+use stylist::css;
+use web_sys::HtmlElement;
+use yew::prelude::*;
+
+use crate::hooks::use_theme;
+use crate::videos::Video;
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub video: Video,
+}
+
+/// A YouTube video that shows its thumbnail and title until it's clicked, and only then loads
+/// the player, so readers who never play it never contact YouTube.
+#[function_component(VideoEmbed)]
+pub fn component(props: &Props) -> Html {
+    let theme = use_theme().theme;
+    let video = props.video;
+    let playing = use_state_eq(|| false);
+    let player = use_node_ref();
+
+    // The button that was focused is gone once the player loads, so focus the player instead.
+    {
+        let player = player.clone();
+        use_effect_with_deps(
+            move |playing| {
+                if let Some(player) = player.cast::<HtmlElement>().filter(|_| *playing) {
+                    let _ = player.focus();
+                }
+            },
+            *playing,
+        );
+    }
+
+    let style = css!(
+        r#"
+            max-width: min(100%, 800px);
+            margin: ${space_md} auto;
+
+            .video-frame {
+                position: relative;
+                aspect-ratio: 16 / 9;
+                overflow: hidden;
+                border-radius: ${radius};
+                background-color: ${code_background};
+            }
+
+            .video-frame > * {
+                position: absolute;
+                inset: 0;
+                width: 100%;
+                height: 100%;
+                border: none;
+            }
+
+            button {
+                display: flex;
+                flex-direction: column;
+                align-items: center;
+                justify-content: center;
+                gap: ${space_sm};
+                padding: ${space_md};
+                background: none;
+                color: ${text};
+                font: inherit;
+                cursor: pointer;
+            }
+
+            button img {
+                position: absolute;
+                inset: 0;
+                width: 100%;
+                max-width: none;
+                height: 100%;
+                object-fit: cover;
+            }
+
+            .video-play {
+                position: relative;
+                display: flex;
+                align-items: center;
+                justify-content: center;
+                width: 68px;
+                height: 48px;
+                border-radius: ${radius};
+                background-color: ${accent};
+                color: ${background};
+                font-size: 1.5em;
+            }
+
+            button:hover .video-play,
+            button:focus-visible .video-play {
+                outline: 3px solid ${text};
+            }
+
+            .video-title {
+                position: relative;
+                padding: ${space_xs} ${space_sm};
+                border-radius: ${radius};
+                background-color: ${background};
+                font-weight: bold;
+            }
+
+            .video-source {
+                margin-top: ${space_sm};
+                font-weight: normal;
+                font-size: 0.9em;
+                text-align: center;
+            }
+        "#,
+        space_xs = theme.spacing.xs,
+        space_sm = theme.spacing.sm,
+        space_md = theme.spacing.md,
+        radius = theme.radii.sm,
+        code_background = theme.colors.code_background,
+        text = theme.colors.text,
+        accent = theme.colors.accent,
+        background = theme.colors.background,
+    );
+
+    let frame = if *playing {
+        html! {
+            <iframe
+                ref={player}
+                src={video.embed_url()}
+                title={video.title}
+                allow="accelerometer; autoplay; clipboard-write; encrypted-media; gyroscope; picture-in-picture"
+                allowfullscreen=true
+            />
+        }
+    } else {
+        let onclick = {
+            let playing = playing.clone();
+            Callback::from(move |_| playing.set(true))
+        };
+        html! {
+            <button type="button" {onclick} aria-label={format!("Play video: {}", video.title)}>
+                <img
+                    src={video.thumbnail.url}
+                    alt=""
+                    width={video.thumbnail.width.to_string()}
+                    height={video.thumbnail.height.to_string()}
+                    loading="lazy"
+                    decoding="async"
+                />
+                <span class="video-play" aria-hidden="true">{ "▶" }</span>
+                <span class="video-title">{ video.title }</span>
+            </button>
+        }
+    };
+
+    html! {
+        <div class={style}>
+            <div class="video-frame">{ frame }</div>
+            <div class="video-source">
+                <a href={video.watch_url()}>{ format!("{} - {}", video.title, video.channel) }</a>
+                { " (plays from youtube-nocookie.com)" }
+            </div>
+        </div>
+    }
+}
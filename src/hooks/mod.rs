@@ -0,0 +1,5 @@
+mod use_scroll_progress;
+mod use_theme;
+
+pub use use_scroll_progress::*;
+pub use use_theme::*;
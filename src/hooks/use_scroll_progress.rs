@@ -0,0 +1,36 @@
+use gloo_events::EventListener;
+use yew::prelude::*;
+
+/// How far the page has been scrolled, from `0.0` at the top to `1.0` at the bottom.
+fn scroll_progress() -> f64 {
+    let root = gloo_utils::document_element();
+    let scrollable = root.scroll_height() - root.client_height();
+    if scrollable <= 0 {
+        return 1.0;
+    }
+
+    (f64::from(root.scroll_top()) / f64::from(scrollable)).clamp(0.0, 1.0)
+}
+
+/// Tracks the page's scroll progress, re-rendering the caller whenever it changes.
+#[hook]
+pub fn use_scroll_progress() -> f64 {
+    let progress = use_state_eq(scroll_progress);
+
+    {
+        let progress = progress.clone();
+        use_effect_with_deps(
+            move |_| {
+                progress.set(scroll_progress());
+                let listener = EventListener::new(&gloo_utils::window(), "scroll", move |_| {
+                    progress.set(scroll_progress());
+                });
+
+                move || drop(listener)
+            },
+            (),
+        );
+    }
+
+    *progress
+}
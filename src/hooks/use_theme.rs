@@ -0,0 +1,13 @@
+use yew::prelude::*;
+
+use crate::theme::ThemeContext;
+
+/// The active theme and a setter for it.
+///
+/// # Panics
+///
+/// Panics if the caller isn't rendered inside a [`ThemeProvider`](crate::theme::ThemeProvider).
+#[hook]
+pub fn use_theme() -> ThemeContext {
+    use_context::<ThemeContext>().expect("use_theme must be called inside a ThemeProvider")
+}
@@ -1,19 +1,45 @@
 use yew::prelude::*;
 use yew_router::prelude::*;
 
+// Declared first so their generated `asset!`, `markdown!`, `include_file!` and `video!` macros are
+// in scope everywhere else.
+#[macro_use]
+mod assets;
+#[macro_use]
+mod markdown;
+#[macro_use]
+mod snippets;
+#[macro_use]
+mod videos;
+
+mod components;
+mod hooks;
 mod pages;
+mod posts;
 mod router;
+mod styles;
+mod theme;
 
+use components::scroll_manager::ScrollManager;
+use components::site_footer::SiteFooter;
+use components::site_header::SiteHeader;
 use router::{switch, Route};
-
-pub const STYLE_FILE: &str = include_str!("styles/blog.css");
+use theme::ThemeProvider;
 
 #[function_component]
 fn App() -> Html {
     html! {
-        <BrowserRouter>
-            <Switch<Route> render={switch} />
-        </BrowserRouter>
+        <ThemeProvider>
+            <BrowserRouter>
+                <SiteHeader />
+                <ScrollManager>
+                    <main>
+                        <Switch<Route> render={switch} />
+                    </main>
+                </ScrollManager>
+                <SiteFooter />
+            </BrowserRouter>
+        </ThemeProvider>
     }
 }
 
@@ -0,0 +1,2 @@
+// Generated from the posts by the build script: the `markdown!` macro.
+include!(concat!(env!("OUT_DIR"), "/markdown.rs"));
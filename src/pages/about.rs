@@ -0,0 +1,15 @@
+use yew::prelude::*;
+
+use crate::components::page::Page;
+use crate::posts::SITE_TITLE;
+
+#[function_component(About)]
+pub fn component() -> Html {
+    html! {
+        <Page>
+            <h1>{ format!("About {SITE_TITLE}") }</h1>
+            <p>{ "A small blog about building for the web with Rust. The site itself is written in Rust with " } <a href="https://yew.rs/">{ "Yew" }</a> { ", compiled to WebAssembly and deployed to GitHub Pages, and the posts walk through how it was made." }</p>
+            <p>{ "The source code for " } <a href="https://github.com/M-Kokolich/rust-site">{ "this site" }</a> { " is available on GitHub." }</p>
+        </Page>
+    }
+}
@@ -0,0 +1,24 @@
+use yew::prelude::*;
+
+use crate::components::page::Page;
+use crate::components::post_list::PostList;
+use crate::posts;
+
+#[function_component(Archive)]
+pub fn component() -> Html {
+    // Posts are listed newest first, so each year's posts are already contiguous.
+    let mut years: Vec<u16> = posts::listed().map(|post| post.date.year).collect();
+    years.dedup();
+
+    html! {
+        <Page>
+            <h1>{ "Archive" }</h1>
+            { for years.into_iter().map(|year| html! {
+                <>
+                    <h2>{ year }</h2>
+                    <PostList posts={posts::listed().filter(|post| post.date.year == year).collect::<Vec<_>>()} />
+                </>
+            }) }
+        </Page>
+    }
+}
@@ -1,10 +1,15 @@
 use yew::prelude::*;
 
+use crate::components::page::Page;
+use crate::components::post_list::PostList;
+use crate::posts::{self, SITE_TITLE};
+
 #[function_component(Home)]
 pub fn component() -> Html {
     html! {
-        <div>
-            <h1>{ "Supa Site" }</h1>
-        </div>
+        <Page>
+            <h1>{ SITE_TITLE }</h1>
+            <PostList posts={posts::listed().collect::<Vec<_>>()} />
+        </Page>
     }
 }
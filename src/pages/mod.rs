@@ -1,3 +1,8 @@
+pub mod about;
+pub mod archive;
 pub mod blog1;
 pub mod blog2;
 pub mod home;
+pub mod search;
+pub mod tag;
+pub mod tags;
@@ -0,0 +1,45 @@
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::components::page::Page;
+use crate::components::post_list::PostList;
+use crate::posts;
+
+#[function_component(Search)]
+pub fn component() -> Html {
+    let query = use_state(String::new);
+
+    let oninput = {
+        let query = query.clone();
+        Callback::from(move |e: InputEvent| {
+            query.set(e.target_unchecked_into::<HtmlInputElement>().value());
+        })
+    };
+
+    let needle = query.trim().to_lowercase();
+    let posts: Vec<_> = posts::listed()
+        .filter(|post| {
+            post.title.to_lowercase().contains(&needle)
+                || post.tags.iter().any(|tag| tag.contains(&needle))
+        })
+        .collect();
+
+    html! {
+        <Page>
+            <h1>{ "Search" }</h1>
+            <input
+                class="search-input"
+                type="search"
+                placeholder="Search posts by title or tag"
+                aria-label="Search posts"
+                value={(*query).clone()}
+                {oninput}
+            />
+            if posts.is_empty() {
+                <p>{ "No posts match your search." }</p>
+            } else {
+                <PostList {posts} />
+            }
+        </Page>
+    }
+}
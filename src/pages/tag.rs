@@ -0,0 +1,31 @@
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use crate::components::page::Page;
+use crate::components::post_list::PostList;
+use crate::posts;
+use crate::router::Route;
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub tag: String,
+}
+
+#[function_component(Tag)]
+pub fn component(props: &Props) -> Html {
+    let posts: Vec<_> = posts::listed()
+        .filter(|post| post.tags.contains(&props.tag.as_str()))
+        .collect();
+
+    html! {
+        <Page>
+            <h1>{ format!("#{}", props.tag) }</h1>
+            if posts.is_empty() {
+                <p>{ "No posts have this tag yet." }</p>
+            } else {
+                <PostList {posts} />
+            }
+            <h3><Link<Route> to={Route::Tags}>{ "All tags" }</Link<Route>></h3>
+        </Page>
+    }
+}
@@ -0,0 +1,22 @@
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use crate::components::page::Page;
+use crate::router::Route;
+
+#[function_component(Tags)]
+pub fn component() -> Html {
+    html! {
+        <Page>
+            <h1>{ "Tags" }</h1>
+            <ul class="post-list">
+                { for crate::posts::tags().into_iter().map(|(tag, count)| html! {
+                    <li>
+                        <h2><Link<Route> to={Route::Tag { tag: tag.to_string() }}>{ format!("#{tag}") }</Link<Route>></h2>
+                        <h3 class="reading-time">{ if count == 1 { "1 post".to_string() } else { format!("{count} posts") } }</h3>
+                    </li>
+                }) }
+            </ul>
+        </Page>
+    }
+}
@@ -0,0 +1,142 @@
+use std::fmt;
+
+use crate::router::Route;
+
+// Generated from `posts.toml` by the build script.
+include!(concat!(env!("OUT_DIR"), "/posts.rs"));
+
+/// Average adult silent reading speed, in words per minute.
+const WORDS_PER_MINUTE: usize = 200;
+
+#[derive(Clone, PartialEq)]
+pub struct Post {
+    pub slug: &'static str,
+    pub route: Route,
+    pub title: &'static str,
+    pub date: Date,
+    pub tags: &'static [&'static str],
+    pub words: usize,
+    pub draft: bool,
+    /// When the post last changed after it was published.
+    pub updated: Option<Date>,
+    /// Notable edits since the post was published, newest first.
+    pub revisions: &'static [Revision],
+    /// Crates the post was written against that the site has since moved past.
+    pub outdated: &'static [OutdatedCrate],
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct Revision {
+    pub date: Date,
+    pub note: &'static str,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct OutdatedCrate {
+    pub name: &'static str,
+    /// The version in `posts.toml`, eg. `0.20`.
+    pub written_for: &'static str,
+    /// The version in `Cargo.lock`, or `None` if the site no longer uses the crate.
+    pub current: Option<&'static str>,
+}
+
+impl Post {
+    /// Whether the post is finished and its date has come.
+    pub fn is_published(&self) -> bool {
+        !self.draft && self.date <= today()
+    }
+
+    pub fn reading_minutes(&self) -> usize {
+        self.words.div_ceil(WORDS_PER_MINUTE).max(1)
+    }
+
+    pub fn reading_time(&self) -> String {
+        format!("{} min read", self.reading_minutes())
+    }
+}
+
+/// Today in UTC by the reader's clock, or the day the site was built as of if that's later, so
+/// scheduled posts go live on their date without the site being rebuilt.
+pub fn today() -> Date {
+    let now = js_sys::Date::new_0();
+    let today = Date::new(
+        now.get_utc_full_year() as u16,
+        now.get_utc_month() as u8 + 1,
+        now.get_utc_date() as u8,
+    );
+    today.max(BUILT_ON)
+}
+
+/// The posts to list, newest first: the published ones, or every post in a preview build.
+pub fn listed() -> impl Iterator<Item = &'static Post> {
+    POSTS
+        .iter()
+        .filter(|post| cfg!(feature = "preview") || post.is_published())
+}
+
+/// Whether unpublished posts can be viewed, because this is a preview build or the URL has a
+/// `?preview` flag.
+pub fn preview() -> bool {
+    cfg!(feature = "preview")
+        || gloo_utils::window()
+            .location()
+            .search()
+            .is_ok_and(|search| {
+                search
+                    .trim_start_matches('?')
+                    .split('&')
+                    .any(|param| param == "preview" || param.starts_with("preview="))
+            })
+}
+
+/// Every tag used by at least one listed post, alphabetically, with the number of posts using it.
+pub fn tags() -> Vec<(&'static str, usize)> {
+    let mut tags: Vec<(&'static str, usize)> = Vec::new();
+    for tag in listed().flat_map(|post| post.tags) {
+        match tags.iter_mut().find(|(name, _)| name == tag) {
+            Some((_, count)) => *count += 1,
+            None => tags.push((tag, 1)),
+        }
+    }
+    tags.sort_unstable();
+    tags
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Date {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl Date {
+    pub const fn new(year: u16, month: u8, day: u8) -> Self {
+        Date { year, month, day }
+    }
+
+    /// `YYYY-MM-DD`, for `<time datetime>`.
+    pub fn iso(&self) -> String {
+        format!("{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const MONTHS: [&str; 12] = [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ];
+        let month = MONTHS[usize::from(self.month - 1)];
+        write!(f, "{} {month} {}", self.day, self.year)
+    }
+}
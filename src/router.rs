@@ -1,4 +1,9 @@
-use crate::pages::{blog1::Blog1, blog2::Blog2, home::Home};
+use crate::components::draft_banner::DraftBanner;
+use crate::pages::{
+    about::About, archive::Archive, blog1::Blog1, blog2::Blog2, home::Home, search::Search,
+    tag::Tag, tags::Tags,
+};
+use crate::posts::{self, Post};
 use yew::prelude::*;
 use yew_router::prelude::*;
 
@@ -6,6 +11,16 @@ use yew_router::prelude::*;
 pub enum Route {
     #[at("/")]
     Home,
+    #[at("/archive")]
+    Archive,
+    #[at("/tags")]
+    Tags,
+    #[at("/tags/:tag")]
+    Tag { tag: String },
+    #[at("/about")]
+    About,
+    #[at("/search")]
+    Search,
     #[at("/blog1")]
     Blog1,
     #[at("/blog2")]
@@ -20,12 +35,39 @@ pub fn switch(routes: Route) -> Html {
         Route::Home => html! {
             <Home />
         },
-        Route::Blog1 => html! {
-            <Blog1 />
+        Route::Archive => html! {
+            <Archive />
         },
-        Route::Blog2 => html! {
-            <Blog2 />
+        Route::Tags => html! {
+            <Tags />
         },
+        Route::Tag { tag } => html! {
+            <Tag {tag} />
+        },
+        Route::About => html! {
+            <About />
+        },
+        Route::Search => html! {
+            <Search />
+        },
+        Route::Blog1 => post(&posts::BLOG1, html! { <Blog1 /> }),
+        Route::Blog2 => post(&posts::BLOG2, html! { <Blog2 /> }),
         Route::NotFound => html! { <h1>{ "404" }</h1> },
     }
 }
+
+/// A post's `page`, which only exists once the post is published unless it's being previewed.
+fn post(post: &Post, page: Html) -> Html {
+    if post.is_published() {
+        page
+    } else if posts::preview() {
+        html! {
+            <>
+                <DraftBanner post={post.clone()} />
+                { page }
+            </>
+        }
+    } else {
+        switch(Route::NotFound)
+    }
+}
@@ -0,0 +1,50 @@
+/// A repo file, or some of its lines, split into highlighted tokens by the build script.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Snippet {
+    /// Path relative to the repo's root, eg. `.github/workflows/continuous_integration.yml`.
+    pub path: &'static str,
+    /// The line number of the first line, which is 1 unless only some lines were included.
+    pub first_line: usize,
+    /// How many lines the whole file has.
+    pub total_lines: usize,
+    pub lines: &'static [&'static [(Highlight, &'static str)]],
+}
+
+impl Snippet {
+    /// The path, followed by the lines shown when it isn't the whole file.
+    pub fn title(&self) -> String {
+        if self.lines.len() == self.total_lines {
+            self.path.to_string()
+        } else {
+            let last = self.first_line + self.lines.len() - 1;
+            format!("{} (lines {}-{last})", self.path, self.first_line)
+        }
+    }
+}
+
+/// How a token is coloured, from the syntax scopes the build script found it in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Highlight {
+    Plain,
+    Comment,
+    String,
+    Constant,
+    Keyword,
+    Name,
+}
+
+impl Highlight {
+    pub fn class(self) -> Option<&'static str> {
+        match self {
+            Highlight::Plain => None,
+            Highlight::Comment => Some("hl-comment"),
+            Highlight::String => Some("hl-string"),
+            Highlight::Constant => Some("hl-constant"),
+            Highlight::Keyword => Some("hl-keyword"),
+            Highlight::Name => Some("hl-name"),
+        }
+    }
+}
+
+// Generated from the posts by the build script: the `include_file!` macro.
+include!(concat!(env!("OUT_DIR"), "/snippets.rs"));
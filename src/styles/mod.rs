@@ -0,0 +1,4 @@
+//! Stylesheets in this directory are validated by the build script and compiled into `css!`
+//! invocations, one function per file, so loading them at runtime can't fail.
+
+include!(concat!(env!("OUT_DIR"), "/styles.rs"));
@@ -0,0 +1,305 @@
+use gloo_storage::{LocalStorage, Storage};
+use stylist::yew::Global;
+use stylist::{css, StyleSource};
+use yew::prelude::*;
+
+/// `localStorage` key holding the reader's explicit theme choice.
+const STORAGE_KEY: &str = "theme";
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ThemeKind {
+    Light,
+    Dark,
+}
+
+impl ThemeKind {
+    pub fn toggled(self) -> Self {
+        match self {
+            ThemeKind::Light => ThemeKind::Dark,
+            ThemeKind::Dark => ThemeKind::Light,
+        }
+    }
+
+    pub fn theme(self) -> &'static Theme {
+        match self {
+            ThemeKind::Light => &LIGHT,
+            ThemeKind::Dark => &DARK,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            ThemeKind::Light => "light",
+            ThemeKind::Dark => "dark",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "light" => Some(ThemeKind::Light),
+            "dark" => Some(ThemeKind::Dark),
+            _ => None,
+        }
+    }
+
+    /// The theme the reader picked last time, if they ever used the toggle.
+    fn stored() -> Option<Self> {
+        LocalStorage::get::<String>(STORAGE_KEY)
+            .ok()
+            .and_then(|value| Self::parse(&value))
+    }
+
+    fn store(self) {
+        let _ = LocalStorage::set(STORAGE_KEY, self.as_str());
+    }
+
+    /// The theme requested by the OS/browser through `prefers-color-scheme`, defaulting to dark.
+    fn system() -> Self {
+        let prefers_light = gloo_utils::window()
+            .match_media("(prefers-color-scheme: light)")
+            .ok()
+            .flatten()
+            .is_some_and(|query| query.matches());
+
+        if prefers_light {
+            ThemeKind::Light
+        } else {
+            ThemeKind::Dark
+        }
+    }
+}
+
+/// Design tokens shared by every component.
+///
+/// Components interpolate these directly into `css!`; the plain stylesheets in `styles/` read the
+/// same values through the CSS custom properties set by [`ThemeProvider`].
+#[derive(PartialEq)]
+pub struct Theme {
+    pub colors: Colors,
+    pub fonts: Fonts,
+    pub spacing: Spacing,
+    pub radii: Radii,
+    pub breakpoints: Breakpoints,
+}
+
+#[derive(PartialEq)]
+pub struct Colors {
+    pub page_background: &'static str,
+    pub background: &'static str,
+    pub text: &'static str,
+    pub muted: &'static str,
+    pub border: &'static str,
+    pub link: &'static str,
+    pub link_visited: &'static str,
+    pub code_background: &'static str,
+    pub accent: &'static str,
+    /// Callout borders and titles, by kind.
+    pub note: &'static str,
+    pub tip: &'static str,
+    pub warning: &'static str,
+    pub danger: &'static str,
+}
+
+#[derive(PartialEq)]
+pub struct Fonts {
+    pub body: &'static str,
+    pub mono: &'static str,
+}
+
+#[derive(PartialEq)]
+pub struct Spacing {
+    pub xs: &'static str,
+    pub sm: &'static str,
+    pub md: &'static str,
+    pub lg: &'static str,
+    pub xl: &'static str,
+}
+
+#[derive(PartialEq)]
+pub struct Radii {
+    pub sm: &'static str,
+    pub lg: &'static str,
+    pub round: &'static str,
+}
+
+/// Viewport widths, in pixels, at which layouts collapse for smaller screens.
+#[derive(PartialEq)]
+pub struct Breakpoints {
+    /// Wide enough for sidenotes to fit in the margins beside a post.
+    pub wide: u32,
+    pub tablet: u32,
+    pub mobile: u32,
+}
+
+impl Breakpoints {
+    /// Media query matching viewports narrower than `width`.
+    pub fn below(width: u32) -> String {
+        format!("(max-width: {}px)", width - 1)
+    }
+
+    /// Media query matching viewports at least `width` wide.
+    pub fn above(width: u32) -> String {
+        format!("(min-width: {width}px)")
+    }
+}
+
+const FONTS: Fonts = Fonts {
+    body: "'Roboto', sans-serif",
+    mono: "'Roboto Mono', monospace",
+};
+
+const SPACING: Spacing = Spacing {
+    xs: "3px",
+    sm: "10px",
+    md: "20px",
+    lg: "40px",
+    xl: "100px",
+};
+
+const RADII: Radii = Radii {
+    sm: "10px",
+    lg: "20px",
+    round: "50%",
+};
+
+const BREAKPOINTS: Breakpoints = Breakpoints {
+    wide: 1400,
+    tablet: 900,
+    mobile: 600,
+};
+
+pub const DARK: Theme = Theme {
+    colors: Colors {
+        page_background: "rgb(0, 19, 47)",
+        background: "rgb(0, 0, 0)",
+        text: "white",
+        muted: "#6b6b6b",
+        border: "#c3c3c3",
+        link: "rgb(179, 255, 245)",
+        link_visited: "rgb(255, 179, 223)",
+        code_background: "#1e1e1e",
+        accent: "rgb(179, 255, 245)",
+        note: "rgb(88, 166, 255)",
+        tip: "rgb(63, 185, 80)",
+        warning: "rgb(210, 153, 34)",
+        danger: "rgb(248, 81, 73)",
+    },
+    fonts: FONTS,
+    spacing: SPACING,
+    radii: RADII,
+    breakpoints: BREAKPOINTS,
+};
+
+pub const LIGHT: Theme = Theme {
+    colors: Colors {
+        page_background: "rgb(225, 234, 245)",
+        background: "rgb(255, 255, 255)",
+        text: "rgb(20, 20, 20)",
+        muted: "#6b6b6b",
+        border: "#3c3c3c",
+        link: "rgb(0, 102, 153)",
+        link_visited: "rgb(150, 40, 110)",
+        code_background: "#ececec",
+        accent: "rgb(0, 102, 153)",
+        note: "rgb(9, 105, 218)",
+        tip: "rgb(26, 127, 55)",
+        warning: "rgb(154, 103, 0)",
+        danger: "rgb(207, 34, 46)",
+    },
+    fonts: FONTS,
+    spacing: SPACING,
+    radii: RADII,
+    breakpoints: BREAKPOINTS,
+};
+
+impl Theme {
+    fn global_style(&self) -> StyleSource {
+        let Theme {
+            colors,
+            fonts,
+            spacing,
+            radii,
+            ..
+        } = self;
+
+        css!(
+            r#"
+                --page-background: ${page_background};
+                --background: ${background};
+                --text: ${text};
+                --muted: ${muted};
+                --border: ${border};
+                --link: ${link};
+                --link-visited: ${link_visited};
+                --code-background: ${code_background};
+                --accent: ${accent};
+                --font-body: ${font_body};
+                --font-mono: ${font_mono};
+                --space-xs: ${space_xs};
+                --space-sm: ${space_sm};
+                --space-md: ${space_md};
+                --space-lg: ${space_lg};
+                --space-xl: ${space_xl};
+                --radius-sm: ${radius_sm};
+                --radius-lg: ${radius_lg};
+                background-color: var(--page-background);
+            "#,
+            page_background = colors.page_background,
+            background = colors.background,
+            text = colors.text,
+            muted = colors.muted,
+            border = colors.border,
+            link = colors.link,
+            link_visited = colors.link_visited,
+            code_background = colors.code_background,
+            accent = colors.accent,
+            font_body = fonts.body,
+            font_mono = fonts.mono,
+            space_xs = spacing.xs,
+            space_sm = spacing.sm,
+            space_md = spacing.md,
+            space_lg = spacing.lg,
+            space_xl = spacing.xl,
+            radius_sm = radii.sm,
+            radius_lg = radii.lg,
+        )
+    }
+}
+
+#[derive(Clone, PartialEq)]
+pub struct ThemeContext {
+    pub kind: ThemeKind,
+    pub theme: &'static Theme,
+    /// Switches theme and remembers the choice for future visits.
+    pub set_kind: Callback<ThemeKind>,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub children: Children,
+}
+
+/// Applies the active theme to the document and shares it with descendants via [`ThemeContext`].
+#[function_component(ThemeProvider)]
+pub fn theme_provider(props: &Props) -> Html {
+    let kind = use_state_eq(|| ThemeKind::stored().unwrap_or_else(ThemeKind::system));
+
+    let context = {
+        let kind_handle = kind.clone();
+        ThemeContext {
+            kind: *kind,
+            theme: kind.theme(),
+            set_kind: Callback::from(move |next: ThemeKind| {
+                next.store();
+                kind_handle.set(next);
+            }),
+        }
+    };
+
+    html! {
+        <ContextProvider<ThemeContext> {context}>
+            <Global css={kind.theme().global_style()} />
+            { for props.children.iter() }
+        </ContextProvider<ThemeContext>>
+    }
+}
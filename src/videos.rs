@@ -0,0 +1,27 @@
+use crate::assets::ImageAsset;
+
+/// A YouTube video, as described in `videos.toml`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Video {
+    pub id: &'static str,
+    pub title: &'static str,
+    pub channel: &'static str,
+    pub thumbnail: ImageAsset,
+}
+
+impl Video {
+    /// The privacy-enhanced player, which doesn't set cookies until the video plays.
+    pub fn embed_url(&self) -> String {
+        format!(
+            "https://www.youtube-nocookie.com/embed/{}?autoplay=1",
+            self.id
+        )
+    }
+
+    pub fn watch_url(&self) -> String {
+        format!("https://www.youtube.com/watch?v={}", self.id)
+    }
+}
+
+// Generated from `videos.toml` by the build script: the `video!` macro.
+include!(concat!(env!("OUT_DIR"), "/videos.rs"));
@@ -0,0 +1,5 @@
+//! The Rust examples in posts, extracted by the build script so `cargo test` checks they still
+//! compile, and that the `runnable` ones run. They're a test crate of their own rather than part of
+//! the site, so like a reader's copy they only see the crates the site depends on.
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
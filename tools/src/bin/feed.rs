@@ -0,0 +1,58 @@
+//! Writes `feed.xml` next to the built site, and links to it from the site's `index.html`.
+//!
+//! Run by Trunk after every build (see `Trunk.toml`), or by hand:
+//!
+//! ```text
+//! cargo run -p site-tools --bin feed -- dist
+//! ```
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use site_content::{feed, Date, Manifest};
+
+fn main() {
+    // Trunk stages the build in a temporary directory and exposes it to hooks through the
+    // environment.
+    let out_dir = env::args()
+        .nth(1)
+        .or_else(|| env::var("TRUNK_STAGING_DIR").ok())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("dist"));
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let manifest = Manifest::load(&root).unwrap_or_else(|e| {
+        eprintln!("error: {e}");
+        process::exit(1);
+    });
+
+    let now = Date::now().unwrap_or_else(|e| {
+        eprintln!("error: {e}");
+        process::exit(1);
+    });
+
+    let path = out_dir.join("feed.xml");
+    if let Err(e) = fs::write(&path, feed::atom(&manifest, now)) {
+        eprintln!("error: failed to write {}: {e}", path.display());
+        process::exit(1);
+    }
+
+    let path = out_dir.join("index.html");
+    let html = fs::read_to_string(&path).unwrap_or_else(|e| {
+        eprintln!("error: failed to read {}: {e}", path.display());
+        process::exit(1);
+    });
+    let Some(html) = feed::with_link(&manifest.site, &html) else {
+        eprintln!(
+            "error: {} has no </head> to link the feed from",
+            path.display()
+        );
+        process::exit(1);
+    };
+    if let Err(e) = fs::write(&path, html) {
+        eprintln!("error: failed to write {}: {e}", path.display());
+        process::exit(1);
+    }
+}
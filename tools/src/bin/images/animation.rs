@@ -0,0 +1,214 @@
+//! Converts animated GIFs to AV1 WebM videos, which are a fraction of the size and, unlike a GIF,
+//! can be paused.
+
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+use image::codecs::gif::GifDecoder;
+use image::{AnimationDecoder, Frame, RgbImage};
+use rav1e::color::{
+    ColorDescription, ColorPrimaries, MatrixCoefficients, PixelRange, TransferCharacteristics,
+};
+use rav1e::prelude::*;
+use site_content::images;
+
+use crate::webm;
+
+/// rav1e's speed preset (0-10) and quantizer (0-255, lower is better).
+const SPEED: u8 = 8;
+const QUANTIZER: usize = 100;
+
+/// The `seq_profile` bits of the `av1C` box's second byte, which shares them with
+/// `seq_level_idx_0`.
+const AV1C_PROFILE_MASK: u8 = 0b1110_0000;
+
+/// Browsers show GIF frames with a delay this short for 100ms instead, so the video does too.
+const MIN_DELAY_MS: u64 = 20;
+const DEFAULT_DELAY_MS: u64 = 100;
+
+/// Every frame of the GIF at `path`, composited, with how long each is shown for in milliseconds.
+pub fn frames(path: &Path) -> Result<Vec<(RgbImage, u64)>, String> {
+    let file = File::open(path).map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+    let frames = GifDecoder::new(BufReader::new(file))
+        .and_then(|decoder| decoder.into_frames().collect_frames())
+        .map_err(|e| format!("failed to decode {}: {e}", path.display()))?;
+
+    Ok(frames
+        .into_iter()
+        .map(|frame| (flatten(&frame), delay(&frame)))
+        .collect())
+}
+
+fn delay(frame: &Frame) -> u64 {
+    let (numer, denom) = frame.delay().numer_denom_ms();
+    let ms = u64::from(numer) / u64::from(denom.max(1));
+    if ms < MIN_DELAY_MS {
+        DEFAULT_DELAY_MS
+    } else {
+        ms
+    }
+}
+
+/// The frame on a white background, since video has no transparency.
+fn flatten(frame: &Frame) -> RgbImage {
+    let rgba = frame.buffer();
+    RgbImage::from_fn(rgba.width(), rgba.height(), |x, y| {
+        let [r, g, b, a] = rgba.get_pixel(x, y).0;
+        let over_white =
+            |c: u8| ((u16::from(c) * u16::from(a) + 255 * (255 - u16::from(a))) / 255) as u8;
+        image::Rgb([over_white(r), over_white(g), over_white(b)])
+    })
+}
+
+/// Encodes `frames` as a WebM video.
+pub fn encode(frames: &[(RgbImage, u64)]) -> Result<Vec<u8>, String> {
+    let (first, _) = frames.first().ok_or("GIF has no frames")?;
+    let (width, height) = first.dimensions();
+
+    let config = Config::new().with_encoder_config(EncoderConfig {
+        width: width as usize,
+        height: height as usize,
+        // Only used for rate control, which a fixed quantizer doesn't do.
+        time_base: Rational::new(1, 1000),
+        chroma_sampling: ChromaSampling::Cs420,
+        bit_depth: 8,
+        pixel_range: PixelRange::Limited,
+        color_description: Some(ColorDescription {
+            color_primaries: ColorPrimaries::BT601,
+            transfer_characteristics: TransferCharacteristics::BT601,
+            matrix_coefficients: MatrixCoefficients::BT601,
+        }),
+        level_idx: Some(images::VIDEO_LEVEL),
+        quantizer: QUANTIZER,
+        speed_settings: SpeedSettings::from_preset(SPEED),
+        ..Default::default()
+    });
+    let mut context: Context<u8> = config
+        .new_context()
+        .map_err(|e| format!("invalid encoder settings: {e}"))?;
+
+    let mut timestamps = Vec::with_capacity(frames.len());
+    let mut duration = 0;
+    for (image, delay) in frames {
+        let mut frame = context.new_frame();
+        let [y, u, v] = yuv420(image);
+        let chroma_width = (width as usize).div_ceil(2);
+        frame.planes[0].copy_from_raw_u8(&y, width as usize, 1);
+        frame.planes[1].copy_from_raw_u8(&u, chroma_width, 1);
+        frame.planes[2].copy_from_raw_u8(&v, chroma_width, 1);
+        context
+            .send_frame(frame)
+            .map_err(|e| format!("failed to encode frame: {e}"))?;
+
+        timestamps.push(duration);
+        duration += delay;
+    }
+    context.flush();
+
+    let mut blocks = Vec::new();
+    let mut sequence_header = None;
+    loop {
+        match context.receive_packet() {
+            Ok(packet) => {
+                let (obus, header) = strip_packet(&packet.data);
+                sequence_header = sequence_header.or(header);
+                blocks.push(webm::Block {
+                    timestamp: timestamps[packet.input_frameno as usize],
+                    keyframe: packet.frame_type == FrameType::KEY,
+                    data: obus,
+                });
+            }
+            Err(EncoderStatus::Encoded) => {}
+            Err(EncoderStatus::LimitReached) => break,
+            Err(e) => return Err(format!("failed to encode frame: {e}")),
+        }
+    }
+
+    // The Matroska mapping wants the sequence header in the `av1C` box as well as the stream.
+    // rav1e always writes level 31 ("unconstrained") into the box itself, so it's set to the
+    // level the stream is encoded at, which the box has to match.
+    let mut codec_private = context.container_sequence_header();
+    codec_private[1] = (codec_private[1] & AV1C_PROFILE_MASK) | images::VIDEO_LEVEL;
+    codec_private.extend(sequence_header.ok_or("encoder produced no sequence header")?);
+
+    Ok(webm::av1(width, height, &codec_private, &blocks, duration))
+}
+
+/// BT.601 limited-range planes for `image`, with chroma averaged over each 2x2 block.
+fn yuv420(image: &RgbImage) -> [Vec<u8>; 3] {
+    let (width, height) = image.dimensions();
+    let luma = |[r, g, b]: [u8; 3]| {
+        let [r, g, b] = [r, g, b].map(i32::from);
+        (((66 * r + 129 * g + 25 * b + 128) >> 8) + 16) as u8
+    };
+    let y = image.pixels().map(|pixel| luma(pixel.0)).collect();
+
+    let mut u = Vec::new();
+    let mut v = Vec::new();
+    for cy in (0..height).step_by(2) {
+        for cx in (0..width).step_by(2) {
+            let (mut r, mut g, mut b, mut n) = (0, 0, 0, 0);
+            for (x, y) in [(cx, cy), (cx + 1, cy), (cx, cy + 1), (cx + 1, cy + 1)] {
+                if x < width && y < height {
+                    let [pr, pg, pb] = image.get_pixel(x, y).0.map(i32::from);
+                    (r, g, b, n) = (r + pr, g + pg, b + pb, n + 1);
+                }
+            }
+            let (r, g, b) = (r / n, g / n, b / n);
+            u.push((((-38 * r - 74 * g + 112 * b + 128) >> 8) + 128) as u8);
+            v.push((((112 * r - 94 * g - 18 * b + 128) >> 8) + 128) as u8);
+        }
+    }
+    [y, u, v]
+}
+
+const OBU_SEQUENCE_HEADER: u8 = 1;
+const OBU_TEMPORAL_DELIMITER: u8 = 2;
+
+/// Drops the temporal delimiters Matroska doesn't want from a packet's OBUs, returning what's
+/// left and the packet's sequence header OBU, if it has one.
+fn strip_packet(data: &[u8]) -> (Vec<u8>, Option<Vec<u8>>) {
+    let mut kept = Vec::with_capacity(data.len());
+    let mut sequence_header = None;
+
+    let mut rest = data;
+    while let Some(&header) = rest.first() {
+        let obu_type = (header >> 3) & 0xF;
+        let has_extension = header & 0x4 != 0;
+        let has_size = header & 0x2 != 0;
+
+        let mut offset = 1 + usize::from(has_extension);
+        let payload = if has_size {
+            let (size, read) = leb128(&rest[offset..]);
+            offset += read;
+            size as usize
+        } else {
+            rest.len() - offset
+        };
+        let (obu, tail) = rest.split_at((offset + payload).min(rest.len()));
+        rest = tail;
+
+        match obu_type {
+            OBU_TEMPORAL_DELIMITER => {}
+            OBU_SEQUENCE_HEADER => {
+                sequence_header.get_or_insert_with(|| obu.to_vec());
+                kept.extend(obu);
+            }
+            _ => kept.extend(obu),
+        }
+    }
+    (kept, sequence_header)
+}
+
+/// Decodes an unsigned LEB128 number, returning it and how many bytes it took.
+fn leb128(bytes: &[u8]) -> (u64, usize) {
+    let mut value = 0;
+    for (i, &byte) in bytes.iter().take(8).enumerate() {
+        value |= u64::from(byte & 0x7F) << (7 * i);
+        if byte & 0x80 == 0 {
+            return (value, i + 1);
+        }
+    }
+    (value, bytes.len().min(8))
+}
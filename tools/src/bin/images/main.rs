@@ -0,0 +1,181 @@
+//! Writes a content-hashed copy and the resized AVIF and WebP variants of every image under
+//! `img/` next to the built site, and converts GIFs to video.
+//!
+//! Run by Trunk after every build (see `Trunk.toml`), or by hand:
+//!
+//! ```text
+//! cargo run -p site-tools --bin images -- dist
+//! ```
+//!
+//! Encoding is slow, so variants and videos are cached in `target/images` and only re-encoded
+//! when their source image changes.
+
+mod animation;
+mod webm;
+
+use std::env;
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use image::codecs::avif::AvifEncoder;
+use image::codecs::jpeg::JpegEncoder;
+use image::codecs::webp::WebPEncoder;
+use image::imageops::FilterType;
+use image::{DynamicImage, GenericImageView, ImageReader};
+use site_content::images::{self, Format, Variant};
+use site_content::IMAGES_DIR;
+
+/// rav1e's speed (1-10) and quality (1-100) settings; encodes all of `img/` in under a minute,
+/// with results that are hard to tell apart from the originals.
+const AVIF_SPEED: u8 = 8;
+const AVIF_QUALITY: u8 = 70;
+
+/// JPEG quality (1-100) of the poster frames shown before a GIF's video plays.
+const POSTER_QUALITY: u8 = 85;
+
+fn main() {
+    let out_dir = env::args()
+        .nth(1)
+        .or_else(|| env::var("TRUNK_STAGING_DIR").ok())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("dist"));
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let cache_dir = root.join("target").join("images");
+
+    if let Err(e) = run(&root, &cache_dir, &out_dir) {
+        eprintln!("error: {e}");
+        process::exit(1);
+    }
+}
+
+fn run(root: &Path, cache_dir: &Path, out_dir: &Path) -> Result<(), String> {
+    for source in images::find(&root.join(IMAGES_DIR))? {
+        let path = source
+            .strip_prefix(root)
+            .unwrap()
+            .components()
+            .map(|c| c.as_os_str().to_str().unwrap())
+            .collect::<Vec<_>>()
+            .join("/");
+
+        // Sniff the format rather than trusting the extension: `fish-full.png` is really a WebP.
+        let reader = ImageReader::open(&source)
+            .and_then(|reader| reader.with_guessed_format())
+            .map_err(|e| format!("failed to read {}: {e}", source.display()))?;
+        let (width, height) = reader
+            .into_dimensions()
+            .map_err(|e| format!("failed to read {}: {e}", source.display()))?;
+        let contents =
+            fs::read(&source).map_err(|e| format!("failed to read {}: {e}", source.display()))?;
+        let hashed_path = images::hashed_path(&path, &contents);
+        let variants = images::variants(&hashed_path, width, height);
+        let animation = images::animation(&hashed_path);
+
+        let stale: Vec<&Variant> = variants
+            .iter()
+            .filter(|variant| is_stale(&cache_dir.join(&variant.path), &source))
+            .collect();
+        if !stale.is_empty() {
+            let image = ImageReader::open(&source)
+                .and_then(|reader| reader.with_guessed_format())
+                .map_err(|e| format!("failed to read {}: {e}", source.display()))?
+                .decode()
+                .map_err(|e| format!("failed to decode {}: {e}", source.display()))?;
+            for variant in stale {
+                encode(&image, variant, &cache_dir.join(&variant.path))?;
+            }
+        }
+
+        if let Some(animation) = &animation {
+            let video = cache_dir.join(&animation.video);
+            let poster = cache_dir.join(&animation.poster);
+            if is_stale(&video, &source) || is_stale(&poster, &source) {
+                convert(&source, &video, &poster)?;
+            }
+        }
+
+        copy(&source, &out_dir.join(&hashed_path))?;
+        for variant in &variants {
+            copy(&cache_dir.join(&variant.path), &out_dir.join(&variant.path))?;
+        }
+        for path in animation.iter().flat_map(|a| [&a.video, &a.poster]) {
+            copy(&cache_dir.join(path), &out_dir.join(path))?;
+        }
+    }
+    Ok(())
+}
+
+/// Whether `cached` is missing or older than the `source` image it was encoded from.
+fn is_stale(cached: &Path, source: &Path) -> bool {
+    let modified = |path: &Path| fs::metadata(path).and_then(|meta| meta.modified()).ok();
+    match (modified(cached), modified(source)) {
+        (Some(cached), Some(source)) => cached < source,
+        _ => true,
+    }
+}
+
+fn encode(image: &DynamicImage, variant: &Variant, path: &Path) -> Result<(), String> {
+    let resized = if variant.width == image.dimensions().0 {
+        image.to_rgba8()
+    } else {
+        image
+            .resize_exact(variant.width, variant.height, FilterType::Lanczos3)
+            .to_rgba8()
+    };
+    let resized = DynamicImage::ImageRgba8(resized);
+
+    create_parent(path)?;
+    let file =
+        File::create(path).map_err(|e| format!("failed to create {}: {e}", path.display()))?;
+    let writer = BufWriter::new(file);
+    let result = match variant.format {
+        Format::Avif => resized.write_with_encoder(AvifEncoder::new_with_speed_quality(
+            writer,
+            AVIF_SPEED,
+            AVIF_QUALITY,
+        )),
+        Format::Webp => resized.write_with_encoder(WebPEncoder::new_lossless(writer)),
+    };
+    result.map_err(|e| {
+        // Don't leave a truncated file behind to be mistaken for a fresh one next time.
+        let _ = fs::remove_file(path);
+        format!("failed to encode {}: {e}", path.display())
+    })
+}
+
+/// Writes the GIF at `source` as a video, and its first frame as a poster.
+fn convert(source: &Path, video: &Path, poster: &Path) -> Result<(), String> {
+    let frames = animation::frames(source)?;
+    let encoded = animation::encode(&frames)
+        .map_err(|e| format!("failed to convert {}: {e}", source.display()))?;
+    create_parent(video)?;
+    fs::write(video, encoded).map_err(|e| format!("failed to write {}: {e}", video.display()))?;
+
+    let file =
+        File::create(poster).map_err(|e| format!("failed to create {}: {e}", poster.display()))?;
+    frames[0]
+        .0
+        .write_with_encoder(JpegEncoder::new_with_quality(
+            BufWriter::new(file),
+            POSTER_QUALITY,
+        ))
+        .map_err(|e| {
+            let _ = fs::remove_file(poster);
+            format!("failed to encode {}: {e}", poster.display())
+        })
+}
+
+fn copy(from: &Path, to: &Path) -> Result<(), String> {
+    create_parent(to)?;
+    fs::copy(from, to)
+        .map(drop)
+        .map_err(|e| format!("failed to copy {} to {}: {e}", from.display(), to.display()))
+}
+
+fn create_parent(path: &Path) -> Result<(), String> {
+    let parent = path.parent().unwrap();
+    fs::create_dir_all(parent).map_err(|e| format!("failed to create {}: {e}", parent.display()))
+}
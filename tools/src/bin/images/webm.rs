@@ -0,0 +1,265 @@
+//! Just enough of a WebM muxer to wrap a single AV1 video track for `<video>`.
+//!
+//! See the Matroska element specification (<https://www.matroska.org/technical/elements.html>)
+//! and its AV1 codec mapping (<https://github.com/ietf-wg-cellar/matroska-specification/blob/master/codec/av1.md>).
+
+// Element IDs, which include their own length marker bits.
+const EBML: u32 = 0x1A45_DFA3;
+const EBML_VERSION: u32 = 0x4286;
+const EBML_READ_VERSION: u32 = 0x42F7;
+const EBML_MAX_ID_LENGTH: u32 = 0x42F2;
+const EBML_MAX_SIZE_LENGTH: u32 = 0x42F3;
+const DOC_TYPE: u32 = 0x4282;
+const DOC_TYPE_VERSION: u32 = 0x4287;
+const DOC_TYPE_READ_VERSION: u32 = 0x4285;
+const SEGMENT: u32 = 0x1853_8067;
+const INFO: u32 = 0x1549_A966;
+const TIMESTAMP_SCALE: u32 = 0x2A_D7B1;
+const DURATION: u32 = 0x4489;
+const MUXING_APP: u32 = 0x4D80;
+const WRITING_APP: u32 = 0x5741;
+const TRACKS: u32 = 0x1654_AE6B;
+const TRACK_ENTRY: u32 = 0xAE;
+const TRACK_NUMBER: u32 = 0xD7;
+const TRACK_UID: u32 = 0x73C5;
+const TRACK_TYPE: u32 = 0x83;
+const FLAG_LACING: u32 = 0x9C;
+const CODEC_ID: u32 = 0x86;
+const CODEC_PRIVATE: u32 = 0x63A2;
+const VIDEO: u32 = 0xE0;
+const PIXEL_WIDTH: u32 = 0xB0;
+const PIXEL_HEIGHT: u32 = 0xBA;
+const CLUSTER: u32 = 0x1F43_B675;
+const TIMESTAMP: u32 = 0xE7;
+const SIMPLE_BLOCK: u32 = 0xA3;
+
+const TRACK: u64 = 1;
+const TRACK_TYPE_VIDEO: u64 = 1;
+const KEYFRAME: u8 = 0x80;
+
+/// One encoded frame.
+pub struct Block {
+    /// When the frame is shown, in milliseconds from the start of the video.
+    pub timestamp: u64,
+    pub keyframe: bool,
+    pub data: Vec<u8>,
+}
+
+/// A complete WebM file holding one `width`x`height` AV1 track, where `codec_private` is the
+/// stream's `av1C` box (including its sequence header OBU) and the video lasts `duration`
+/// milliseconds.
+pub fn av1(
+    width: u32,
+    height: u32,
+    codec_private: &[u8],
+    blocks: &[Block],
+    duration: u64,
+) -> Vec<u8> {
+    let header = element(
+        EBML,
+        &[
+            uint(EBML_VERSION, 1),
+            uint(EBML_READ_VERSION, 1),
+            uint(EBML_MAX_ID_LENGTH, 4),
+            uint(EBML_MAX_SIZE_LENGTH, 8),
+            string(DOC_TYPE, "webm"),
+            uint(DOC_TYPE_VERSION, 4),
+            uint(DOC_TYPE_READ_VERSION, 2),
+        ]
+        .concat(),
+    );
+
+    let info = element(
+        INFO,
+        &[
+            uint(TIMESTAMP_SCALE, 1_000_000),
+            float(DURATION, duration as f64),
+            string(MUXING_APP, env!("CARGO_PKG_NAME")),
+            string(WRITING_APP, env!("CARGO_PKG_NAME")),
+        ]
+        .concat(),
+    );
+
+    let video = element(
+        VIDEO,
+        &[
+            uint(PIXEL_WIDTH, width.into()),
+            uint(PIXEL_HEIGHT, height.into()),
+        ]
+        .concat(),
+    );
+    let tracks = element(
+        TRACKS,
+        &element(
+            TRACK_ENTRY,
+            &[
+                uint(TRACK_NUMBER, TRACK),
+                uint(TRACK_UID, TRACK),
+                uint(TRACK_TYPE, TRACK_TYPE_VIDEO),
+                uint(FLAG_LACING, 0),
+                string(CODEC_ID, "V_AV1"),
+                element(CODEC_PRIVATE, codec_private),
+                video,
+            ]
+            .concat(),
+        ),
+    );
+
+    let mut segment = [info, tracks].concat();
+    segment.extend(clusters(blocks));
+
+    [header, element(SEGMENT, &segment)].concat()
+}
+
+/// Groups `blocks` into clusters, starting a new one at every keyframe and whenever a block's
+/// timestamp no longer fits in the 16 bits it's stored relative to its cluster in.
+fn clusters(blocks: &[Block]) -> Vec<u8> {
+    let mut out = Vec::new();
+    let mut cluster: Option<(u64, Vec<u8>)> = None;
+
+    for block in blocks {
+        let fits = cluster
+            .as_ref()
+            .is_some_and(|(start, _)| block.timestamp - start <= i16::MAX as u64);
+        if block.keyframe || !fits {
+            if let Some((start, body)) = cluster.take() {
+                out.extend(finish_cluster(start, body));
+            }
+            cluster = Some((block.timestamp, Vec::new()));
+        }
+
+        let (start, body) = cluster.as_mut().unwrap();
+        let relative = (block.timestamp - *start) as i16;
+        let mut simple_block = vec![0x80 | TRACK as u8];
+        simple_block.extend(relative.to_be_bytes());
+        simple_block.push(if block.keyframe { KEYFRAME } else { 0 });
+        simple_block.extend(&block.data);
+        body.extend(element(SIMPLE_BLOCK, &simple_block));
+    }
+
+    if let Some((start, body)) = cluster {
+        out.extend(finish_cluster(start, body));
+    }
+    out
+}
+
+fn finish_cluster(start: u64, blocks: Vec<u8>) -> Vec<u8> {
+    element(CLUSTER, &[uint(TIMESTAMP, start), blocks].concat())
+}
+
+fn element(id: u32, body: &[u8]) -> Vec<u8> {
+    let id = id.to_be_bytes();
+    let first = id.iter().position(|&byte| byte != 0).unwrap_or(3);
+
+    let mut out = id[first..].to_vec();
+    out.extend(size(body.len() as u64));
+    out.extend(body);
+    out
+}
+
+fn uint(id: u32, value: u64) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    let first = bytes.iter().position(|&byte| byte != 0).unwrap_or(7);
+    element(id, &bytes[first..])
+}
+
+fn float(id: u32, value: f64) -> Vec<u8> {
+    element(id, &value.to_be_bytes())
+}
+
+fn string(id: u32, value: &str) -> Vec<u8> {
+    element(id, value.as_bytes())
+}
+
+/// `len` as an EBML variable-length integer, in as few bytes as possible. All ones is reserved
+/// for "unknown size", hence the `- 1`.
+fn size(len: u64) -> Vec<u8> {
+    let width = (1..=8).find(|&width| len < (1 << (7 * width)) - 1).unwrap();
+    let marked = len | (1 << (7 * width));
+    marked.to_be_bytes()[8 - width..].to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Reads an EBML variable-length integer from the start of `bytes`, returning its value and
+    /// length.
+    fn read_size(bytes: &[u8]) -> (u64, usize) {
+        let width = bytes[0].leading_zeros() as usize + 1;
+        let value = bytes[1..width]
+            .iter()
+            .fold(u64::from(bytes[0]) & (0xFF >> width), |value, &byte| {
+                value << 8 | u64::from(byte)
+            });
+        (value, width)
+    }
+
+    #[test]
+    fn size_round_trips() {
+        let lens = (0..=8).flat_map(|width| {
+            let max = (1u64 << (7 * width)).saturating_sub(1);
+            [max.saturating_sub(1), max, max + 1]
+        });
+        for len in lens.filter(|&len| len < (1 << 56) - 1) {
+            let encoded = size(len);
+            assert_eq!(read_size(&encoded), (len, encoded.len()), "{len}");
+        }
+    }
+
+    #[test]
+    fn size_uses_fewest_bytes() {
+        assert_eq!(size(0), [0x80]);
+        assert_eq!(size(126), [0xFE]);
+        assert_eq!(size(127), [0x40, 0x7F]);
+        assert_eq!(size(16382), [0x7F, 0xFE]);
+        assert_eq!(size(16383), [0x20, 0x3F, 0xFF]);
+    }
+
+    #[test]
+    fn size_never_means_unknown() {
+        // All value bits set is reserved for "unknown size", at every width.
+        for width in 1..=7 {
+            let unknown = (1u64 << (7 * width)) - 1;
+            assert!(size(unknown).len() > width, "{unknown}");
+        }
+    }
+
+    #[test]
+    fn element_keeps_id_marker_and_prefixes_size() {
+        assert_eq!(element(TRACK_ENTRY, &[1, 2]), [0xAE, 0x82, 1, 2]);
+        assert_eq!(element(DURATION, &[]), [0x44, 0x89, 0x80]);
+        assert_eq!(&element(EBML, &[])[..5], [0x1A, 0x45, 0xDF, 0xA3, 0x80]);
+    }
+
+    #[test]
+    fn uint_drops_leading_zeros() {
+        assert_eq!(uint(TRACK_NUMBER, 1), [0xD7, 0x81, 1]);
+        assert_eq!(uint(TRACK_NUMBER, 0), [0xD7, 0x81, 0]);
+        assert_eq!(
+            uint(TIMESTAMP_SCALE, 1_000_000),
+            [0x2A, 0xD7, 0xB1, 0x83, 0x0F, 0x42, 0x40]
+        );
+    }
+
+    #[test]
+    fn clusters_start_at_keyframes_and_when_timestamps_overflow() {
+        let block = |timestamp, keyframe| Block {
+            timestamp,
+            keyframe,
+            data: vec![0],
+        };
+        let count = |blocks: &[Block]| {
+            let out = clusters(blocks);
+            let id = CLUSTER.to_be_bytes();
+            out.windows(4).filter(|window| *window == id).count()
+        };
+
+        assert_eq!(count(&[block(0, true), block(100, false)]), 1);
+        assert_eq!(count(&[block(0, true), block(100, true)]), 2);
+        assert_eq!(
+            count(&[block(0, true), block(i16::MAX as u64 + 1, false)]),
+            2
+        );
+    }
+}
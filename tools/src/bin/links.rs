@@ -0,0 +1,230 @@
+//! Checks every link in the site's sources without touching the network.
+//!
+//! Internal links must match a route in `src/router.rs` or a file the build serves. External
+//! links are looked up in `links.toml` (or the file passed with `--cache`), which records the
+//! status each URL last responded with and, optionally, the anchors on the page:
+//!
+//! ```text
+//! cargo run -p site-tools --bin links -- [--cache links.toml]
+//! ```
+//!
+//! External links that aren't recorded fail the check too, so a new link gets checked by hand
+//! and recorded before it's published.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use site_content::links::{Links, LINKS};
+use site_content::{markdown, source};
+
+/// Directory holding the site's components and pages, relative to the site's root.
+const SOURCE_DIR: &str = "src";
+
+/// The file declaring the site's routes, relative to the site's root.
+const ROUTER: &str = "src/router.rs";
+
+/// Attributes whose value is a URL, including `Quote`'s `source`.
+const LINK_ATTRIBUTES: [&str; 3] = ["href", "source", "src"];
+
+/// Files the post-build hooks write into the site, which aren't in the repo.
+const GENERATED: [&str; 1] = ["feed.xml"];
+
+/// A link that points somewhere it shouldn't, or couldn't be checked.
+struct Problem {
+    file: PathBuf,
+    line: usize,
+    url: String,
+    message: String,
+}
+
+fn main() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let mut cache = root.join(LINKS);
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--cache" => match args.next() {
+                Some(path) => cache = PathBuf::from(path),
+                None => fail("--cache needs a path"),
+            },
+            _ => fail(&format!("unknown argument {arg}")),
+        }
+    }
+
+    let problems = check(&root, &cache).unwrap_or_else(|e| fail(&e));
+
+    for problem in &problems {
+        report(&root, problem);
+    }
+
+    if !problems.is_empty() {
+        process::exit(1);
+    }
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("error: {message}");
+    process::exit(1);
+}
+
+fn report(root: &Path, problem: &Problem) {
+    let file = problem.file.strip_prefix(root).unwrap_or(&problem.file);
+    eprintln!(
+        "error: {}:{}: {}: {}",
+        file.display(),
+        problem.line,
+        problem.url,
+        problem.message
+    );
+}
+
+/// Checks every link under `src/`, including those in `markdown!`, returning the broken ones and
+/// the external ones `cache` has nothing recorded for, in the order they appear.
+fn check(root: &Path, cache: &Path) -> Result<Vec<Problem>, String> {
+    let links = if cache.exists() {
+        Links::load(cache)?
+    } else {
+        Links::default()
+    };
+    let routes = routes(root)?;
+    let cache_name = cache.strip_prefix(root).unwrap_or(cache).display();
+
+    let mut problems = Vec::new();
+    for file in sources(&root.join(SOURCE_DIR))? {
+        let text = fs::read_to_string(&file)
+            .map_err(|e| format!("failed to read {}: {e}", file.display()))?;
+        let literals = source::literals(&text);
+        let ids: Vec<&str> = literals
+            .iter()
+            .filter(|literal| literal.attribute.as_deref() == Some("id"))
+            .map(|literal| literal.value.as_str())
+            .collect();
+
+        let mut urls: Vec<(String, usize)> = literals
+            .iter()
+            .filter(|literal| {
+                literal
+                    .attribute
+                    .as_deref()
+                    .is_some_and(|attribute| LINK_ATTRIBUTES.contains(&attribute))
+            })
+            .map(|literal| (literal.value.clone(), literal.line))
+            .collect();
+        for block in markdown::find(&text) {
+            urls.extend(
+                markdown::links(&block.markdown)
+                    .into_iter()
+                    .map(|(url, line)| (url, block.line + line)),
+            );
+        }
+
+        for (url, line) in &urls {
+            let url = url.as_str();
+            let (target, fragment) = match url.split_once('#') {
+                Some((target, fragment)) => (target, Some(fragment)),
+                None => (url, None),
+            };
+            let result = if url.starts_with("http://") || url.starts_with("https://") {
+                match links.get(target) {
+                    None => Err(format!("not recorded in {cache_name}")),
+                    Some(link) if !(200..300).contains(&link.status) => {
+                        Err(format!("responded with {}", link.status))
+                    }
+                    Some(link) => match (fragment, &link.anchors) {
+                        (Some(fragment), Some(anchors))
+                            if !anchors.iter().any(|anchor| anchor == fragment) =>
+                        {
+                            Err(format!("page has no #{fragment}"))
+                        }
+                        _ => Ok(()),
+                    },
+                }
+            } else if target.is_empty() {
+                match fragment {
+                    Some(fragment) if !ids.contains(&fragment) => {
+                        Err(format!("no id=\"{fragment}\" in this file"))
+                    }
+                    _ => Ok(()),
+                }
+            } else if let Some(path) = target.strip_prefix('/') {
+                let path = path.split('?').next().unwrap_or_default();
+                let exists = routes.iter().any(|route| matches_route(route, path))
+                    || GENERATED.contains(&path)
+                    || (!path.is_empty() && root.join(path).is_file());
+                if exists {
+                    Ok(())
+                } else {
+                    Err("matches no route or file".to_string())
+                }
+            } else if url.contains(':') {
+                // mailto:, data: and the like.
+                Ok(())
+            } else {
+                Err("relative links resolve differently on every route, use /path".to_string())
+            };
+
+            if let Err(message) = result {
+                problems.push(Problem {
+                    file: file.clone(),
+                    line: *line,
+                    url: url.to_string(),
+                    message,
+                });
+            }
+        }
+    }
+
+    Ok(problems)
+}
+
+/// Every `.rs` file under `dir`, sorted so the report comes out in a stable order.
+fn sources(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let mut files = Vec::new();
+    let entries =
+        fs::read_dir(dir).map_err(|e| format!("failed to read {}: {e}", dir.display()))?;
+    for entry in entries {
+        let path = entry
+            .map_err(|e| format!("failed to read {}: {e}", dir.display()))?
+            .path();
+        if path.is_dir() {
+            files.extend(sources(&path)?);
+        } else if path.extension().is_some_and(|extension| extension == "rs") {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// The path of every `#[at("...")]` on `Route`, without the leading slash.
+fn routes(root: &Path) -> Result<Vec<String>, String> {
+    let path = root.join(ROUTER);
+    let text =
+        fs::read_to_string(&path).map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+    let routes: Vec<String> = text
+        .split("#[at(\"")
+        .skip(1)
+        .filter_map(|rest| rest.split_once('"'))
+        .map(|(route, _)| route.trim_start_matches('/').to_string())
+        .collect();
+
+    if routes.is_empty() {
+        return Err(format!("found no routes in {}", path.display()));
+    }
+    Ok(routes)
+}
+
+/// Whether `path` is matched by `route`, where `:name` segments match any one segment.
+fn matches_route(route: &str, path: &str) -> bool {
+    let route: Vec<&str> = route.split('/').collect();
+    let path: Vec<&str> = path.trim_end_matches('/').split('/').collect();
+    route.len() == path.len()
+        && route.iter().zip(&path).all(|(route, path)| {
+            route
+                .strip_prefix(':')
+                .map_or(route == path, |_| !path.is_empty())
+        })
+}
@@ -0,0 +1,257 @@
+//! A stand-in for the Rust Playground that runs the `runnable` examples in posts locally.
+//!
+//! Speaks the same `POST /execute` JSON as play.rust-lang.org, so the site can be pointed at
+//! either one (see `PLAYGROUND_URL` in `src/components/runnable_code.rs`):
+//!
+//! ```text
+//! cargo run -p site-tools --bin playground -- [--port 3001] [--allow-origin http://127.0.0.1:8080]
+//! ```
+//!
+//! Examples are compiled with the `rustc` on the `PATH`, without any crates, and killed if they
+//! run for longer than a few seconds. It runs whatever it's sent, so it only listens on localhost
+//! and only answers pages served from the origins given with `--allow-origin` (by default,
+//! `trunk serve`'s), so other sites open in the browser can't use it.
+
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+use std::process::{self, Command, Output, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+const DEFAULT_PORT: u16 = 3001;
+
+/// Where `trunk serve` serves the site by default, under both names for localhost.
+const DEFAULT_ORIGINS: [&str; 2] = ["http://127.0.0.1:8080", "http://localhost:8080"];
+
+/// How much of an example's stdout, and of its stderr, is kept. The rest is read and dropped.
+const MAX_OUTPUT: u64 = 64 * 1024;
+
+/// Appended to output that was cut off at `MAX_OUTPUT`.
+const TRUNCATED: &[u8] = b"\n[output truncated]\n";
+
+/// How long an example gets to compile, and then to run.
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The parts of the Playground's request this server understands. Others, like `channel`, are
+/// ignored.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Execute {
+    code: String,
+    #[serde(default = "default_edition")]
+    edition: String,
+    #[serde(default)]
+    mode: String,
+}
+
+fn default_edition() -> String {
+    "2021".to_string()
+}
+
+#[derive(Serialize)]
+struct Executed {
+    success: bool,
+    stdout: String,
+    stderr: String,
+}
+
+fn main() {
+    let mut port = DEFAULT_PORT;
+    let mut origins = Vec::new();
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--port" => match args.next().and_then(|port| port.parse().ok()) {
+                Some(value) => port = value,
+                None => fail("--port needs a port number"),
+            },
+            "--allow-origin" => match args.next() {
+                Some(origin) => origins.push(origin.trim_end_matches('/').to_string()),
+                None => fail("--allow-origin needs an origin, eg. http://127.0.0.1:8080"),
+            },
+            _ => fail(&format!("unknown argument {arg}")),
+        }
+    }
+
+    let server = Server::http(("127.0.0.1", port))
+        .unwrap_or_else(|e| fail(&format!("failed to listen on port {port}: {e}")));
+    if origins.is_empty() {
+        origins = DEFAULT_ORIGINS.map(String::from).to_vec();
+    }
+    eprintln!(
+        "listening on http://127.0.0.1:{port}/execute for {}",
+        origins.join(", ")
+    );
+
+    for request in server.incoming_requests() {
+        if let Err(e) = respond(request, &origins) {
+            eprintln!("error: {e}");
+        }
+    }
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("error: {message}");
+    process::exit(1);
+}
+
+/// Answers `request` if it comes from a page served from one of `origins`.
+fn respond(mut request: Request, origins: &[String]) -> Result<(), String> {
+    let origin = request
+        .headers()
+        .iter()
+        .find(|header| header.field.equiv("Origin"))
+        .map(|header| header.value.to_string())
+        .filter(|origin| origins.contains(origin));
+    let Some(origin) = origin else {
+        return request
+            .respond(Response::from_string("origin not allowed").with_status_code(403))
+            .map_err(|e| format!("failed to respond: {e}"));
+    };
+
+    // The site is served from another port, so browsers ask before posting to this one.
+    let cors = [
+        ("Access-Control-Allow-Origin", origin.as_str()),
+        ("Access-Control-Allow-Methods", "POST"),
+        ("Access-Control-Allow-Headers", "Content-Type"),
+        ("Vary", "Origin"),
+    ];
+    let with_cors = |response: Response<_>| {
+        cors.iter().fold(response, |response, (name, value)| {
+            response.with_header(Header::from_bytes(*name, *value).unwrap())
+        })
+    };
+
+    let response = match (request.method(), request.url()) {
+        (Method::Options, _) => with_cors(Response::from_string("")),
+        (Method::Post, "/execute") => {
+            let mut body = String::new();
+            request
+                .as_reader()
+                .read_to_string(&mut body)
+                .map_err(|e| format!("failed to read request: {e}"))?;
+            match serde_json::from_str::<Execute>(&body) {
+                Ok(execute) => {
+                    let executed = execute_code(&execute);
+                    let json = serde_json::to_string(&executed).unwrap();
+                    with_cors(Response::from_string(json)).with_header(
+                        Header::from_bytes("Content-Type", "application/json").unwrap(),
+                    )
+                }
+                Err(e) => with_cors(Response::from_string(e.to_string())).with_status_code(400),
+            }
+        }
+        _ => with_cors(Response::from_string("not found")).with_status_code(404),
+    };
+    request
+        .respond(response)
+        .map_err(|e| format!("failed to respond: {e}"))
+}
+
+/// Compiles and runs `execute`'s code in a directory of its own, which is removed afterwards.
+fn execute_code(execute: &Execute) -> Executed {
+    let dir = env::temp_dir().join(format!("site-playground-{}", process::id()));
+    let result = compile_and_run(&dir, execute);
+    let _ = fs::remove_dir_all(&dir);
+
+    result.unwrap_or_else(|e| Executed {
+        success: false,
+        stdout: String::new(),
+        stderr: e,
+    })
+}
+
+fn compile_and_run(dir: &Path, execute: &Execute) -> Result<Executed, String> {
+    fs::create_dir_all(dir).map_err(|e| format!("failed to create {}: {e}", dir.display()))?;
+    let source = dir.join("main.rs");
+    fs::write(&source, &execute.code)
+        .map_err(|e| format!("failed to write {}: {e}", source.display()))?;
+    let binary = dir.join("main");
+
+    // Relative paths keep the temporary directory out of error messages.
+    let mut rustc = Command::new("rustc");
+    rustc
+        .current_dir(dir)
+        .arg("--edition")
+        .arg(&execute.edition)
+        .arg("-o")
+        .arg("main")
+        .arg("main.rs");
+    if execute.mode == "release" {
+        rustc.arg("-O");
+    }
+    let compiled = run(&mut rustc)?;
+    if !compiled.status.success() {
+        return Ok(Executed {
+            success: false,
+            stdout: String::new(),
+            stderr: String::from_utf8_lossy(&compiled.stderr).into_owned(),
+        });
+    }
+
+    let ran = run(Command::new(&binary).current_dir(dir))?;
+    Ok(Executed {
+        success: ran.status.success(),
+        stdout: String::from_utf8_lossy(&ran.stdout).into_owned(),
+        stderr: String::from_utf8_lossy(&ran.stderr).into_owned(),
+    })
+}
+
+/// Runs `command` to completion, or kills it once it's taken longer than `TIMEOUT`.
+fn run(command: &mut Command) -> Result<Output, String> {
+    let program = command.get_program().to_string_lossy().into_owned();
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("failed to start {program}: {e}"))?;
+
+    // Read as it's written, so a chatty example can't fill the pipe and stall.
+    let stdout = read_all(child.stdout.take());
+    let stderr = read_all(child.stderr.take());
+
+    let started = Instant::now();
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if started.elapsed() > TIMEOUT => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(format!(
+                    "{program} timed out after {} seconds",
+                    TIMEOUT.as_secs()
+                ));
+            }
+            Ok(None) => thread::sleep(Duration::from_millis(50)),
+            Err(e) => return Err(format!("failed to wait for {program}: {e}")),
+        }
+    };
+
+    Ok(Output {
+        status,
+        stdout: stdout.join().unwrap_or_default(),
+        stderr: stderr.join().unwrap_or_default(),
+    })
+}
+
+/// Reads `pipe` until it closes, keeping the first `MAX_OUTPUT` bytes.
+fn read_all(pipe: Option<impl Read + Send + 'static>) -> thread::JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut bytes = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.by_ref().take(MAX_OUTPUT).read_to_end(&mut bytes);
+            // The rest is still read, so the example doesn't block on a full pipe.
+            if io::copy(&mut pipe, &mut io::sink()).unwrap_or_default() > 0 {
+                bytes.extend_from_slice(TRUNCATED);
+            }
+        }
+        bytes
+    })
+}